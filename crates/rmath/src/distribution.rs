@@ -0,0 +1,508 @@
+use std::ops::{Add, Mul};
+
+use crate::{
+    CircularWorldLine, Matrix, StaticWorldLine, Vector3, Vector4, WorldLine, WorldLineError,
+};
+
+/// Gauss–Legendre quadrature rule, exact for polynomials of degree below twice its nodes
+#[derive(Debug, Clone, PartialEq)]
+pub struct GaussLegendre {
+    /// on [-1, 1], in decreasing order
+    nodes: Vec<f64>,
+    weights: Vec<f64>,
+}
+
+impl GaussLegendre {
+    /// n: number of nodes, at least 1
+    pub fn new(n: usize) -> GaussLegendre {
+        let n = n.max(1);
+        let mut nodes = Vec::with_capacity(n);
+        let mut weights = Vec::with_capacity(n);
+        for i in 0..n {
+            // Newton's method on P_n from an asymptotic guess of its i-th root
+            let mut x = (std::f64::consts::PI * (i as f64 + 0.75) / (n as f64 + 0.5)).cos();
+            let mut dp = 1.0;
+            for _ in 0..100 {
+                let (p, d) = GaussLegendre::legendre(n, x);
+                dp = d;
+                let dx = p / d;
+                x -= dx;
+                if dx.abs() < 1e-15 {
+                    break;
+                }
+            }
+            nodes.push(x);
+            weights.push(2.0 / ((1.0 - x * x) * dp * dp));
+        }
+        GaussLegendre { nodes, weights }
+    }
+
+    /// P_n(x) and P_n'(x)
+    fn legendre(n: usize, x: f64) -> (f64, f64) {
+        let (mut p0, mut p1) = (1.0, x);
+        for k in 2..=n {
+            let k = k as f64;
+            (p0, p1) = (p1, ((2.0 * k - 1.0) * x * p1 - (k - 1.0) * p0) / k);
+        }
+        if n == 0 {
+            return (1.0, 0.0);
+        }
+        (p1, n as f64 * (x * p1 - p0) / (x * x - 1.0))
+    }
+
+    /// ∫_a^b f(λ) dλ, or the first error of f
+    pub fn integrate<T, E>(
+        &self,
+        a: f64,
+        b: f64,
+        mut f: impl FnMut(f64) -> Result<T, E>,
+    ) -> Result<T, E>
+    where
+        T: Add<Output = T> + Mul<f64, Output = T>,
+    {
+        let (mid, half) = ((a + b) / 2.0, (b - a) / 2.0);
+        let mut terms = self
+            .nodes
+            .iter()
+            .zip(&self.weights)
+            .map(|(x, w)| Ok(f(mid + half * x)? * (w * half)));
+        let first = terms.next().unwrap()?;
+        terms.try_fold(first, |sum, t| Ok(sum + t?))
+    }
+}
+
+/// Field of a source at an event, split by how it falls off with the distance l,
+/// with its 4-potential
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SourceField {
+    /// Velocity part of field strength, falling off as 1/l²
+    pub near: Matrix,
+    /// Acceleration part of field strength, falling off as 1/l
+    pub radiation: Matrix,
+    /// 4-potential in Lorenz gauge
+    pub potential: Vector4,
+}
+
+impl SourceField {
+    pub fn zero() -> SourceField {
+        SourceField {
+            near: Matrix::zero(),
+            radiation: Matrix::zero(),
+            potential: Vector4::zero(),
+        }
+    }
+
+    pub fn field_strength(&self) -> Matrix {
+        self.near + self.radiation
+    }
+
+    /// Largest component of the field strength
+    fn size(&self) -> f64 {
+        self.field_strength()
+            .rows
+            .iter()
+            .flatten()
+            .fold(0.0_f64, |max, f| max.max(f.abs()))
+    }
+}
+
+impl Add for SourceField {
+    type Output = SourceField;
+
+    fn add(self, rhs: SourceField) -> SourceField {
+        SourceField {
+            near: self.near + rhs.near,
+            radiation: self.radiation + rhs.radiation,
+            potential: self.potential + rhs.potential,
+        }
+    }
+}
+
+impl Mul<f64> for SourceField {
+    type Output = SourceField;
+
+    fn mul(self, rhs: f64) -> SourceField {
+        SourceField {
+            near: self.near * rhs,
+            radiation: self.radiation * rhs,
+            potential: self.potential * rhs,
+        }
+    }
+}
+
+/// Charge of an element and its intersection with a light cone, or why there is none
+pub type ElementIntersection = (f64, Result<(Vector4, Vector3, Vector3), WorldLineError>);
+
+/// Charge spread continuously over a one-parameter family of world lines
+pub trait ChargeDistribution {
+    /// Retarded field at x, each element seen at its own intersection with the past light cone
+    ///
+    /// Elements whose field has not arrived are skipped, and it is an error only if none has.
+    fn past_field(&self, c: f64, x: Vector4) -> Result<SourceField, WorldLineError>;
+
+    /// Advanced field at x, from intersections with the future light cone
+    fn future_field(&self, c: f64, x: Vector4) -> Result<SourceField, WorldLineError>;
+
+    /// n elements evenly spaced in the parameter with their share of the charge
+    /// and their intersections with the past light cone of x, to draw the distribution
+    fn past_elements(&self, c: f64, x: Vector4, n: usize) -> Vec<ElementIntersection>;
+}
+
+/// Family of world lines x(λ) for λ ∈ [0, 1] carrying charge density ρ(λ) per unit λ
+///
+/// Fields are integrated over λ by adaptive Gauss–Legendre quadrature: each of the initial
+/// panels is halved until the halves agree with the whole panel, so that elements near
+/// the observer are sampled finely instead of showing up as separate point charges.
+pub struct WorldLineFamily<F> {
+    /// λ ↦ (ρ(λ), x(λ)), ρ in the units of q of `Matrix::field_strength`
+    element: F,
+    rule: GaussLegendre,
+    panels: usize,
+    tolerance: f64,
+    max_depth: u32,
+}
+
+impl<F, W> WorldLineFamily<F>
+where
+    F: Fn(f64) -> (f64, W),
+    W: WorldLine,
+{
+    /// 4 panels of 6 nodes refined up to 6 times to a relative tolerance of 1e-4
+    pub fn new(element: F) -> WorldLineFamily<F> {
+        WorldLineFamily {
+            element,
+            rule: GaussLegendre::new(6),
+            panels: 4,
+            tolerance: 1e-4,
+            max_depth: 6,
+        }
+    }
+
+    /// Number of panels [0, 1] is first split into,
+    /// more for a family whose field may cancel between panels
+    pub fn panels(mut self, panels: usize) -> WorldLineFamily<F> {
+        self.panels = panels.max(1);
+        self
+    }
+
+    /// Error allowed relative to the field of the whole family
+    pub fn tolerance(mut self, tolerance: f64) -> WorldLineFamily<F> {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Times a panel may be halved
+    pub fn max_depth(mut self, max_depth: u32) -> WorldLineFamily<F> {
+        self.max_depth = max_depth;
+        self
+    }
+
+    fn field(&self, c: f64, x: Vector4, future: bool) -> Result<SourceField, WorldLineError> {
+        let mut arrived = false;
+        let mut not_arrived = WorldLineError::SpaceLike;
+        let mut element = |lambda: f64| {
+            let (rho, wl) = (self.element)(lambda);
+            let intersection = if future {
+                wl.future_intersection(c, x)
+            } else {
+                wl.past_intersection(c, x)
+            };
+            match intersection {
+                Ok((y, u, a)) => {
+                    arrived = true;
+                    let l = (y - x).spatial();
+                    let (near, radiation, potential) = if future {
+                        let (near, radiation) = Matrix::advanced_field_strength_parts(rho, l, u, a);
+                        (near, radiation, Vector4::advanced_four_potential(rho, l, u))
+                    } else {
+                        let (near, radiation) = Matrix::field_strength_parts(rho, l, u, a);
+                        (near, radiation, Vector4::four_potential(rho, l, u))
+                    };
+                    Ok(SourceField {
+                        near,
+                        radiation,
+                        potential,
+                    })
+                }
+                Err(e) if e.is_not_arrived() => {
+                    not_arrived = e;
+                    Ok(SourceField::zero())
+                }
+                Err(e) => Err(e),
+            }
+        };
+        let width = 1.0 / self.panels as f64;
+        let mut wholes = Vec::with_capacity(self.panels);
+        for i in 0..self.panels {
+            let a = width * i as f64;
+            wholes.push(self.rule.integrate(a, a + width, &mut element)?);
+        }
+        // the absolute error allowed per unit λ
+        let scale = self.tolerance * wholes.iter().map(SourceField::size).sum::<f64>();
+        let mut sum = SourceField::zero();
+        for (i, whole) in wholes.into_iter().enumerate() {
+            let a = width * i as f64;
+            sum = sum + self.refine(&mut element, a, a + width, whole, scale, 0)?;
+        }
+        if arrived {
+            Ok(sum)
+        } else {
+            Err(not_arrived)
+        }
+    }
+
+    /// Integral over [a, b] whose single-panel estimate is whole
+    fn refine(
+        &self,
+        element: &mut impl FnMut(f64) -> Result<SourceField, WorldLineError>,
+        a: f64,
+        b: f64,
+        whole: SourceField,
+        scale: f64,
+        depth: u32,
+    ) -> Result<SourceField, WorldLineError> {
+        let m = (a + b) / 2.0;
+        let left = self.rule.integrate(a, m, &mut *element)?;
+        let right = self.rule.integrate(m, b, &mut *element)?;
+        let halves = left + right;
+        if depth >= self.max_depth || (halves + whole * -1.0).size() <= scale * (b - a) {
+            return Ok(halves);
+        }
+        Ok(self.refine(element, a, m, left, scale, depth + 1)?
+            + self.refine(element, m, b, right, scale, depth + 1)?)
+    }
+}
+
+/// Charge at rest on the segment from a to b, with total charge q spread uniformly
+pub fn static_segment(
+    a: Vector3,
+    b: Vector3,
+    q: f64,
+) -> WorldLineFamily<impl Fn(f64) -> (f64, StaticWorldLine)> {
+    WorldLineFamily::new(move |lambda| (q, StaticWorldLine::new(a + (b - a) * lambda)))
+}
+
+/// Charge at rest on a circle around center in the plane normal to axis,
+/// with total charge q spread uniformly
+pub fn static_ring(
+    center: Vector3,
+    radius: f64,
+    axis: Vector3,
+    q: f64,
+) -> WorldLineFamily<impl Fn(f64) -> (f64, StaticWorldLine)> {
+    let (e1, e2) = ring_basis(radius, axis);
+    WorldLineFamily::new(move |lambda: f64| {
+        let (sin, cos) = (std::f64::consts::TAU * lambda).sin_cos();
+        (q, StaticWorldLine::new(center + e1 * cos + e2 * sin))
+    })
+}
+
+/// Charged ring rotating counterclockwise around axis at frequency, a steady current
+/// of q frequency in the world frame if q is its total charge
+pub fn rotating_ring(
+    center: Vector3,
+    radius: f64,
+    axis: Vector3,
+    frequency: f64,
+    q: f64,
+    c: f64,
+) -> Result<WorldLineFamily<impl Fn(f64) -> (f64, CircularWorldLine)>, WorldLineError> {
+    if axis.magnitude2() <= f64::EPSILON {
+        return Err(WorldLineError::InvalidParameter);
+    }
+    let (e1, e2) = ring_basis(radius, axis);
+    // every element moves alike, so one checks the speed for all
+    CircularWorldLine::new(center, e1, axis, frequency, c)?;
+    Ok(WorldLineFamily::new(move |lambda: f64| {
+        let (sin, cos) = (std::f64::consts::TAU * lambda).sin_cos();
+        let wl = CircularWorldLine::new(center, e1 * cos + e2 * sin, axis, frequency, c)
+            .expect("checked above");
+        (q, wl)
+    }))
+}
+
+/// Orthogonal vectors of length radius normal to axis, with e1 × e2 along axis
+fn ring_basis(radius: f64, axis: Vector3) -> (Vector3, Vector3) {
+    let axis = axis.normalized();
+    let helper = if axis.x.abs() < 0.9 {
+        Vector3::X_AXIS
+    } else {
+        Vector3::Y_AXIS
+    };
+    let e1 = (helper - axis * helper.dot(axis)).normalized();
+    (e1 * radius, axis.cross(e1) * radius)
+}
+
+impl<F, W> ChargeDistribution for WorldLineFamily<F>
+where
+    F: Fn(f64) -> (f64, W),
+    W: WorldLine,
+{
+    fn past_field(&self, c: f64, x: Vector4) -> Result<SourceField, WorldLineError> {
+        self.field(c, x, false)
+    }
+
+    fn future_field(&self, c: f64, x: Vector4) -> Result<SourceField, WorldLineError> {
+        self.field(c, x, true)
+    }
+
+    fn past_elements(&self, c: f64, x: Vector4, n: usize) -> Vec<ElementIntersection> {
+        (0..n)
+            .map(|i| {
+                let (rho, wl) = (self.element)((i as f64 + 0.5) / n as f64);
+                (rho / n as f64, wl.past_intersection(c, x))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{maxwell_residual, vec3, CutOffWorldLine};
+
+    #[test]
+    fn gauss_legendre_is_exact_for_polynomials() {
+        for n in 1..12 {
+            let rule = GaussLegendre::new(n);
+            assert_relative_eq!(rule.weights.iter().sum::<f64>(), 2.0, epsilon = 1e-13);
+            for degree in 0..2 * n as i32 {
+                let integral = rule
+                    .integrate(-0.5, 2.0, |x| Ok::<_, ()>(x.powi(degree)))
+                    .unwrap();
+                let exact =
+                    (2.0_f64.powi(degree + 1) - (-0.5_f64).powi(degree + 1)) / (degree + 1) as f64;
+                assert_relative_eq!(integral, exact, max_relative = 1e-12);
+            }
+        }
+        let rule = GaussLegendre::new(3);
+        assert_relative_eq!(rule.nodes[0], 0.6_f64.sqrt(), epsilon = 1e-15);
+        assert_relative_eq!(rule.nodes[1], 0.0, epsilon = 1e-15);
+        assert_relative_eq!(rule.weights[1], 8.0 / 9.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn static_ring_on_axis() {
+        let (r, q, c) = (2.0, 3.0, 2.0);
+        let ring = static_ring(Vector3::zero(), r, Vector3::Z_AXIS, q);
+        for z in [-3.0, 0.0, 0.1, 5.0] {
+            let x = Vector4::new(0.0, 0.0, z, 10.0);
+            let field = ring.past_field(c, x).unwrap();
+            let e = field.field_strength().field_strength_to_electric_field(1.0);
+            let d2 = r * r + z * z;
+            let ez = q * z / (4.0 * std::f64::consts::PI * d2 * d2.sqrt());
+            assert_relative_eq!(e, vec3(0.0, 0.0, ez), epsilon = 1e-12);
+            assert_relative_eq!(
+                field.potential.ct,
+                q / (4.0 * std::f64::consts::PI * d2.sqrt()),
+                max_relative = 1e-10
+            );
+        }
+    }
+
+    #[test]
+    fn smooth_near_the_ring() {
+        // just beside the wire the field is nearly that of an infinite line charge,
+        // ρ / (2π d) per unit length, which 32 point charges cannot reproduce
+        let (r, d, c) = (2.0, 0.01, 2.0);
+        let line = 1.0 / (std::f64::consts::TAU * r) / (std::f64::consts::TAU * d);
+        let ring = static_ring(Vector3::zero(), r, Vector3::Z_AXIS, 1.0);
+        let reference = static_ring(Vector3::zero(), r, Vector3::Z_AXIS, 1.0)
+            .tolerance(1e-10)
+            .max_depth(16);
+        let e = |field: SourceField| field.field_strength().field_strength_to_electric_field(1.0);
+        for angle in [0.0, 0.05, 0.1] {
+            let (sin, cos) = f64::sin_cos(angle);
+            let x = Vector4::new((r + d) * cos, (r + d) * sin, 0.0, 10.0);
+            let exact = e(reference.past_field(c, x).unwrap());
+            assert_relative_eq!(exact.magnitude(), line, max_relative = 3e-2);
+            assert_relative_eq!(
+                e(ring.past_field(c, x).unwrap()),
+                exact,
+                epsilon = 1e-3 * line
+            );
+            let points = ring
+                .past_elements(c, x, 32)
+                .into_iter()
+                .map(|(q, p)| {
+                    let (y, u, a) = p.unwrap();
+                    e(SourceField {
+                        near: Matrix::field_strength(q, (y - x).spatial(), u, a),
+                        radiation: Matrix::zero(),
+                        potential: Vector4::zero(),
+                    })
+                })
+                .fold(Vector3::zero(), |sum, e| sum + e);
+            assert!(
+                (points - exact).magnitude() > 0.1 * line,
+                "{angle} {points:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn current_loop_on_axis() {
+        // a rotating ring with a static opposite ring, B_z = I r² / (2 (r² + z²)^{3/2})
+        let (r, c, frequency) = (2.0, 3.0, 0.01);
+        let rotating =
+            rotating_ring(Vector3::zero(), r, Vector3::Z_AXIS, frequency, 1.0, c).unwrap();
+        let fixed = static_ring(Vector3::zero(), r, Vector3::Z_AXIS, -1.0);
+        // charge per unit time passing a point, in units where q / (εc) and B share c
+        let current = frequency / c;
+        for z in [0.0, 1.0, 4.0] {
+            let x = Vector4::new(0.0, 0.0, z, 100.0);
+            let fs = rotating.past_field(c, x).unwrap().field_strength()
+                + fixed.past_field(c, x).unwrap().field_strength();
+            let b = fs.field_strength_to_magnetic_field();
+            let bz = current * r * r / (2.0 * (r * r + z * z).powf(1.5));
+            assert_relative_eq!(b, vec3(0.0, 0.0, bz), epsilon = 1e-9);
+            assert_relative_eq!(
+                fs.field_strength_to_electric_field(c).magnitude(),
+                0.0,
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn segment_satisfies_maxwell() {
+        let c = 2.0;
+        let segment = static_segment(vec3(-2.0, 0.0, 0.0), vec3(2.0, 1.0, 0.0), 2.0);
+        for x in [
+            Vector4::new(0.0, 3.0, 1.0, 5.0),
+            Vector4::new(4.0, -1.0, 2.0, 5.0),
+        ] {
+            let field = |x| Ok::<_, WorldLineError>(segment.past_field(c, x)?.field_strength());
+            let residual = maxwell_residual(field, x, 1e-2).unwrap();
+            assert!(residual.relative() < 1e-6, "{x:?} {residual:?}");
+        }
+    }
+
+    #[test]
+    fn partly_arrived() {
+        // a segment along x from 0 to 4 appearing at x_0 = 0, seen from x = -1:
+        // only the part within 3 - 1 = 2 has arrived at x_0 = 3
+        let c = 2.0;
+        let segment = WorldLineFamily::new(|lambda: f64| {
+            let wl = StaticWorldLine::new(vec3(4.0 * lambda, 0.0, 0.0));
+            (1.0, CutOffWorldLine::new(wl, 0.0))
+        })
+        .tolerance(1e-9)
+        .max_depth(12);
+        let x = Vector4::new(-1.0, 0.0, 0.0, 3.0);
+        let e = segment
+            .past_field(c, x)
+            .unwrap()
+            .field_strength()
+            .field_strength_to_electric_field(1.0);
+        let ex = -0.25 / (4.0 * std::f64::consts::PI) * (1.0 - 1.0 / 3.0);
+        assert_relative_eq!(e, vec3(ex, 0.0, 0.0), max_relative = 1e-3);
+        let x = Vector4::new(-1.0, 0.0, 0.0, 0.5);
+        assert_eq!(segment.past_field(c, x), Err(WorldLineError::SpaceLike));
+        let elements = segment.past_elements(c, Vector4::new(-1.0, 0.0, 0.0, 3.0), 4);
+        assert_relative_eq!(elements.iter().map(|(q, _)| q).sum::<f64>(), 1.0);
+        assert_eq!(elements.iter().filter(|(_, p)| p.is_ok()).count(), 2);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Matrix, Vector3};
+use crate::{Matrix, Vector3, Vector4};
 
 impl Matrix {
     /// Calculate field strength with upper indices
@@ -13,19 +13,43 @@ impl Matrix {
             // too near
             return Matrix::zero();
         }
+        Matrix::field_strength_terms(q, l, u, a, 1.0 / l_len)
+    }
+
+    /// Split field strength into the velocity (near, Coulomb) part falling off as 1/l²
+    /// and the acceleration (radiation) part falling off as 1/l.
+    ///
+    /// Same inputs as `field_strength`, which is the sum of the two.
+    pub fn field_strength_parts(q: f64, l: Vector3, u: Vector3, a: Vector3) -> (Matrix, Matrix) {
+        let l_len = l.magnitude();
+        if l_len < f64::EPSILON * 2.0 {
+            // too near
+            return (Matrix::zero(), Matrix::zero());
+        }
+        (
+            Matrix::field_strength_terms(q, l, u, Vector3::zero(), 1.0 / l_len),
+            Matrix::field_strength_terms(q, l, u, a, 0.0),
+        )
+    }
+
+    /// Terms of field strength with a and with near = 1/|l|,
+    /// so that near = 0 leaves only the acceleration terms.
+    fn field_strength_terms(q: f64, l: Vector3, u: Vector3, a: Vector3, near: f64) -> Matrix {
+        let l_len = l.magnitude();
         let l_hat = l / l_len;
         let u_t = u.gamma(); // =u^0 (upper index)
         let a_t = a.dot(u) / u_t; // =a^0 (upper index)
 
         let lu = l_hat.dot(u);
         let la = l_hat.dot(a);
-        let term_1 = l_hat * ((u_t * (la - 1.0 / l_len) - a_t * lu) / u_t.powi(3));
-        let term_2 = u * ((a_t + la - 1.0 / l_len) / u_t.powi(3));
-        let term_3 = a / u_t.powi(2);
+        let d = u_t + lu; // =-(l·u)/|l|, retardation (Doppler) factor
+        let term_1 = l_hat * ((u_t * (la - near) - a_t * lu) / d.powi(3));
+        let term_2 = u * ((a_t + la - near) / d.powi(3));
+        let term_3 = a / d.powi(2);
         let f_t = term_1 + term_2 - term_3; // =F^{0i} (upper index)
 
-        let t_1 = 1.0 / u_t.powi(2);
-        let t_2 = (a_t + la - 1.0 / l_len) / u_t.powi(3);
+        let t_1 = 1.0 / d.powi(2);
+        let t_2 = (a_t + la - near) / d.powi(3);
         let f_xy = (l_hat.x * a.y - l_hat.y * a.x) * t_1 - (l_hat.x * u.y - l_hat.y * u.x) * t_2;
         let f_yz = (l_hat.y * a.z - l_hat.z * a.y) * t_1 - (l_hat.y * u.z - l_hat.z * u.y) * t_2;
         let f_zx = (l_hat.z * a.x - l_hat.x * a.z) * t_1 - (l_hat.z * u.x - l_hat.x * u.z) * t_2;
@@ -42,6 +66,32 @@ impl Matrix {
         ) * (q / l_len / 4.0 / std::f64::consts::PI)
     }
 
+    /// Calculate advanced field strength with upper indices
+    ///
+    /// Same as `field_strength` but l, u and a are of the charge on observer's FLC.
+    /// Time reversal maps the FLC to the PLC, flipping u and the magnetic part.
+    pub fn advanced_field_strength(q: f64, l: Vector3, u: Vector3, a: Vector3) -> Matrix {
+        Matrix::eta() * Matrix::field_strength(q, l, -u, a) * Matrix::eta() * -1.0
+    }
+
+    /// `field_strength_parts` of `advanced_field_strength`
+    pub fn advanced_field_strength_parts(
+        q: f64,
+        l: Vector3,
+        u: Vector3,
+        a: Vector3,
+    ) -> (Matrix, Matrix) {
+        let (near, radiation) = Matrix::field_strength_parts(q, l, -u, a);
+        let reverse = |f: Matrix| Matrix::eta() * f * Matrix::eta() * -1.0;
+        (reverse(near), reverse(radiation))
+    }
+
+    /// Field strength with upper indices from the electric and magnetic fields,
+    /// the inverse of `field_strength_to_electric_field` and `field_strength_to_magnetic_field`
+    pub fn field_strength_from_fields(electric: Vector3, magnetic: Vector3, c: f64) -> Matrix {
+        Matrix::from_electric_magnetic(electric / c, magnetic)
+    }
+
     pub fn field_strength_to_electric_field(&self, c: f64) -> Vector3 {
         Vector3::new(self.rows[3][0], self.rows[3][1], self.rows[3][2]) * c
     }
@@ -49,13 +99,569 @@ impl Matrix {
     pub fn field_strength_to_magnetic_field(&self) -> Vector3 {
         Vector3::new(self.rows[1][2], self.rows[2][0], self.rows[0][1])
     }
+
+    /// Hodge dual *F^{μν} = 1/2 ε^{μνρσ} F_{ρσ}, taking E/c to B and B to -E/c
+    ///
+    /// **F = -F, and the dual of a field strength is the field strength of the
+    /// electric-magnetic dual sources.
+    pub fn hodge_dual(&self) -> Matrix {
+        let f_t = Vector3::new(self.rows[3][0], self.rows[3][1], self.rows[3][2]);
+        Matrix::from_electric_magnetic(self.field_strength_to_magnetic_field(), -f_t)
+    }
+
+    /// Lorentz four-force on a charge q of mass m over the mass,
+    /// du^μ/ds = q/m F^{μν} η_{νρ} u^ρ
+    ///
+    /// u: 4-velocity of the charge, as `Vector4::from_velocity`
+    /// The spatial part is (q/m)(u^0 E/c + u × B), and the result is orthogonal to u.
+    pub fn lorentz_four_force(&self, q: f64, m: f64, u: Vector4) -> Vector4 {
+        *self * (Matrix::eta() * u) * (q / m)
+    }
+
+    /// Landau–Lifshitz radiation reaction on a charge q of mass m over the mass
+    ///
+    /// The jerk of `abraham_lorentz_dirac_force` is replaced by the derivative of
+    /// `lorentz_four_force` along the world line, (q/m)((u·∂)F η u + F η du/ds).
+    /// rate: (u·∂)F, the derivative of self along u
+    /// s0: `radiation_reaction_length`
+    pub fn landau_lifshitz_force(
+        &self,
+        rate: Matrix,
+        q: f64,
+        m: f64,
+        u: Vector4,
+        s0: f64,
+    ) -> Vector4 {
+        let force = self.lorentz_four_force(q, m, u);
+        let jerk = rate.lorentz_four_force(q, m, u) + self.lorentz_four_force(q, m, force);
+        abraham_lorentz_dirac_force(jerk, u, s0)
+    }
+
+    /// E² - c²B², that is -c²/2 F_{μν}F^{μν}
+    pub fn field_strength_invariant(&self, c: f64) -> f64 {
+        let e = self.field_strength_to_electric_field(c);
+        let b = self.field_strength_to_magnetic_field();
+        e.magnitude2() - b.magnitude2() * c * c
+    }
+
+    /// E·B, that is -c/4 F_{μν}*F^{μν}
+    pub fn field_strength_pseudo_invariant(&self, c: f64) -> f64 {
+        let e = self.field_strength_to_electric_field(c);
+        let b = self.field_strength_to_magnetic_field();
+        e.dot(b)
+    }
+
+    /// Classify by the invariants
+    ///
+    /// The field is null if sqrt((E² - c²B²)² + 4(cE·B)²) <= tolerance (E² + c²B²).
+    /// The left side is the energy density in the frame of `parallel_frame_velocity`,
+    /// so the ratio goes from 1 for static fields to 0 for plane waves.
+    pub fn classify_field_strength(&self, c: f64, tolerance: f64) -> FieldClass {
+        let e = self.field_strength_to_electric_field(c);
+        let b = self.field_strength_to_magnetic_field() * c;
+        let s = e.magnitude2() - b.magnitude2();
+        let p = e.dot(b);
+        if (s * s + 4.0 * p * p).sqrt() <= tolerance * (e.magnitude2() + b.magnitude2()) {
+            FieldClass::Null
+        } else if s > 0.0 {
+            FieldClass::ElectricLike
+        } else {
+            FieldClass::MagneticLike
+        }
+    }
+
+    /// Covariant velocity of the frame in which E ∥ B,
+    /// where the magnetic (electric) field vanishes if E·B = 0 and the field is
+    /// electric-like (magnetic-like).
+    ///
+    /// The frame moves along E × B with β / (1 + β²) = |E × cB| / (E² + c²B²).
+    /// None for null fields, for which β would be 1.
+    pub fn parallel_frame_velocity(&self, c: f64) -> Option<Vector3> {
+        let e = self.field_strength_to_electric_field(c);
+        let b = self.field_strength_to_magnetic_field() * c;
+        let energy = e.magnitude2() + b.magnitude2();
+        let poynting = e.cross(b);
+        if poynting.magnitude2() == 0.0 {
+            return Some(Vector3::zero());
+        }
+        let k = poynting.magnitude() / energy;
+        if 2.0 * k >= 1.0 {
+            return None;
+        }
+        let beta = 2.0 * k / (1.0 + (1.0 - 4.0 * k * k).sqrt());
+        Some(poynting.normalized() * (beta / (1.0 - beta * beta).sqrt()))
+    }
+}
+
+/// Kind of a point dipole, with the normalization of its moment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DipoleKind {
+    /// electric dipole moment over εc
+    Electric,
+    /// magnetic dipole moment over εc², that is μm/c
+    Magnetic,
+}
+
+impl Matrix {
+    /// Calculate field strength of a point dipole with upper indices
+    ///
+    /// moment: the moment and its first and second derivatives by cτ along Fermi–Walker
+    ///         transported axes of the dipole's rest frame, at the retarded point,
+    ///         normalized as `kind`
+    /// l: position vector, from observer to dipole, in observer's inertial frame
+    /// u: covariant velocity of dipole on observer's PLC in observer's inertial frame
+    /// a: covariant acceleration of dipole on observer's PLC in observer's inertial frame
+    /// jerk: derivative of a by cτ on observer's PLC in observer's inertial frame
+    pub fn dipole_field_strength(
+        kind: DipoleKind,
+        moment: [Vector3; 3],
+        l: Vector3,
+        u: Vector3,
+        a: Vector3,
+        jerk: Vector3,
+    ) -> Matrix {
+        let (near, radiation) = Matrix::dipole_field_strength_parts(kind, moment, l, u, a, jerk);
+        near + radiation
+    }
+
+    /// Split dipole field strength into the near part falling off as 1/l³ and 1/l²
+    /// and the radiation part falling off as 1/l.
+    ///
+    /// Same inputs as `dipole_field_strength`, which is the sum of the two.
+    pub fn dipole_field_strength_parts(
+        kind: DipoleKind,
+        moment: [Vector3; 3],
+        l: Vector3,
+        u: Vector3,
+        a: Vector3,
+        jerk: Vector3,
+    ) -> (Matrix, Matrix) {
+        let Some(source) = DipoleSource::new(kind, moment, l, u, a, jerk) else {
+            // too near
+            return (Matrix::zero(), Matrix::zero());
+        };
+        let DipoleSource {
+            q: [q, dq, ddq],
+            u,
+            a,
+            k,
+            rho,
+            m,
+            n,
+        } = source;
+        let qk = contract(q, k);
+        let dqk = contract(dq, k);
+        let qu = contract(q, u);
+        // H^{μα} = ∂^α V^μ of A = -V / 4π by powers of 1/ρ
+        let radiation = outer(
+            contract(ddq, k) + dqk * (3.0 * m) + qk * (n + 3.0 * m * m),
+            k,
+        );
+        let near2 = dq * -1.0
+            + q * -m
+            + outer(
+                contract(dq, u) * 2.0
+                    + contract(q, a)
+                    + qu * (3.0 * m)
+                    + dqk * 3.0
+                    + qk * (6.0 * m),
+                k,
+            )
+            + outer(dqk * 2.0 + qk * (3.0 * m), u)
+            + outer(qk, a);
+        let near3 = q * -1.0 + outer(qu, u * 2.0 + k * 3.0) + outer(qk, (u + k) * 3.0);
+        let near = near2 * rho.powi(-2) + near3 * rho.powi(-3);
+        let antisymmetrize =
+            |h: Matrix| (h + h.transposed() * -1.0) * (0.25 / std::f64::consts::PI);
+        (
+            antisymmetrize(near),
+            antisymmetrize(radiation * rho.recip()),
+        )
+    }
+
+    /// `dipole_field_strength_parts` of the advanced field
+    ///
+    /// Same as `dipole_field_strength_parts` but l, u, a, jerk and moment are of the dipole on
+    /// observer's FLC. Time reversal flips the odd derivatives of an electric moment and
+    /// the even derivatives of a magnetic moment, made of currents.
+    pub fn advanced_dipole_field_strength_parts(
+        kind: DipoleKind,
+        moment: [Vector3; 3],
+        l: Vector3,
+        u: Vector3,
+        a: Vector3,
+        jerk: Vector3,
+    ) -> (Matrix, Matrix) {
+        let reversed = reverse_dipole_moment(kind, moment);
+        let (near, radiation) =
+            Matrix::dipole_field_strength_parts(kind, reversed, l, -u, a, -jerk);
+        let reverse = |f: Matrix| Matrix::eta() * f * Matrix::eta() * -1.0;
+        (reverse(near), reverse(radiation))
+    }
+
+    /// Field strength with upper indices from F^{0i} = E/c and B
+    fn from_electric_magnetic(f_t: Vector3, b: Vector3) -> Matrix {
+        Matrix::new(
+            [0.0, b.z, -b.y, -f_t.x],
+            [-b.z, 0.0, b.x, -f_t.y],
+            [b.y, -b.x, 0.0, -f_t.z],
+            [f_t.x, f_t.y, f_t.z, 0.0],
+        )
+    }
+}
+
+/// Moment of a point dipole along Fermi–Walker transported axes, as the dipole functions take it
+///
+/// moment: the moment and its first and second derivatives by cτ in the rest frame reached from
+///         observer's inertial frame by the pure boost `Matrix::lorentz(u)`
+/// u, a, jerk: covariant velocity, acceleration and derivative of a by cτ of the dipole
+///
+/// The boost frame turns against Fermi–Walker transported axes by Thomas precession,
+/// at Ω = u × a / (u^0 + 1) per cτ, so a moment fixed in it still changes.
+pub fn fermi_walker_moment(
+    moment: [Vector3; 3],
+    u: Vector3,
+    a: Vector3,
+    jerk: Vector3,
+) -> [Vector3; 3] {
+    let u_t = u.gamma();
+    let a_t = a.dot(u) / u_t;
+    let omega = u.cross(a) / (u_t + 1.0);
+    let d_omega = u.cross(jerk) / (u_t + 1.0) - u.cross(a) * (a_t / (u_t + 1.0).powi(2));
+    let [p, dp, ddp] = moment;
+    [
+        p,
+        dp + omega.cross(p),
+        ddp + d_omega.cross(p) + omega.cross(dp) * 2.0 + omega.cross(omega.cross(p)),
+    ]
+}
+
+/// Moment of the time reversed dipole
+fn reverse_dipole_moment(kind: DipoleKind, moment: [Vector3; 3]) -> [Vector3; 3] {
+    let [p, dp, ddp] = moment;
+    match kind {
+        DipoleKind::Electric => [p, -dp, ddp],
+        DipoleKind::Magnetic => [-p, dp, -ddp],
+    }
+}
+
+/// A point dipole seen from the observer, in 4-vectors with upper indices
+///
+/// The dipole is the limit of a rigid pair of charges, with the 4-potential
+/// A^μ = -(Q'k/ρ + Qu/ρ² + λ Qk/ρ²) / 4π, where (Qk)^μ = Q^{μν} η_{νρ} k^ρ.
+struct DipoleSource {
+    /// Q^{μν}, u∧p for electric and its dual for magnetic, and its derivatives by cτ
+    q: [Matrix; 3],
+    u: Vector4,
+    a: Vector4,
+    /// -R/ρ for R from the dipole to the observer, with k·u = 1
+    k: Vector4,
+    /// -R·u, the distance in the dipole's rest frame
+    rho: f64,
+    /// R·a/ρ, with λ = 1 + R·a = 1 + ρm
+    m: f64,
+    /// R·jerk/ρ
+    n: f64,
+}
+
+impl DipoleSource {
+    fn new(
+        kind: DipoleKind,
+        moment: [Vector3; 3],
+        l: Vector3,
+        u: Vector3,
+        a: Vector3,
+        jerk: Vector3,
+    ) -> Option<DipoleSource> {
+        let l_len = l.magnitude();
+        if l_len < f64::EPSILON * 2.0 {
+            return None;
+        }
+        let u = Vector4::from_velocity(u);
+        let a = Vector4::from_ctv(a.dot(u.spatial()) / u.ct, a);
+        let jerk = Vector4::from_ctv((a.lorentz_norm2() + u.spatial().dot(jerk)) / u.ct, jerk);
+
+        // moment on Fermi–Walker transported axes, df/ds = u (a·f)
+        let boost = Matrix::lorentz(-u.spatial());
+        let [p, dp, ddp] = moment.map(|p| boost * Vector4::from_ctv(0.0, p));
+        let ddp = ddp + u * (2.0 * a.lorentz_dot(dp) + jerk.lorentz_dot(p)) + a * a.lorentz_dot(p);
+        let dp = dp + u * a.lorentz_dot(p);
+        let wedge = |x: Vector4, y: Vector4| outer(x, y) + outer(y, x) * -1.0;
+        let q = [
+            wedge(u, p),
+            wedge(a, p) + wedge(u, dp),
+            wedge(jerk, p) + wedge(a, dp) * 2.0 + wedge(u, ddp),
+        ];
+        let q = match kind {
+            DipoleKind::Electric => q,
+            DipoleKind::Magnetic => q.map(|q| q.hodge_dual()),
+        };
+
+        let r = Vector4::from_ctv(l_len, -l);
+        let rho = -r.lorentz_dot(u);
+        Some(DipoleSource {
+            q,
+            u,
+            a,
+            k: r * -rho.recip(),
+            rho,
+            m: r.lorentz_dot(a) / rho,
+            n: r.lorentz_dot(jerk) / rho,
+        })
+    }
+}
+
+/// Q^{μν} η_{νρ} v^ρ
+fn contract(q: Matrix, v: Vector4) -> Vector4 {
+    q * (Matrix::eta() * v)
+}
+
+/// Outer product x^μ y^ν
+fn outer(x: Vector4, y: Vector4) -> Matrix {
+    let x = [x.x, x.y, x.z, x.ct];
+    let y = [y.x, y.y, y.z, y.ct];
+    Matrix::new(
+        y.map(|y| x[0] * y),
+        y.map(|y| x[1] * y),
+        y.map(|y| x[2] * y),
+        y.map(|y| x[3] * y),
+    )
+}
+
+impl Matrix {
+    /// Calculate electromagnetic stress-energy tensor with upper indices from field strength
+    ///
+    /// T^{μν} = c² (F^{μα} η_{αβ} F^{νβ} - 1/4 η^{μν} F_{αβ}F^{αβ}),
+    /// in the same units as `field_strength`, where ε = 1.
+    pub fn stress_energy(field_strength: Matrix, c: f64) -> Matrix {
+        let f = field_strength;
+        // F_{αβ}F^{αβ} = 2(B² - E²/c²)
+        let f2 = -2.0 * f.field_strength_invariant(c) / (c * c);
+        (f * Matrix::eta() * f.transposed() + Matrix::eta() * (-0.25 * f2)) * (c * c)
+    }
+
+    /// T^{00} = (E² + c²B²) / 2
+    pub fn stress_energy_to_energy_density(&self) -> f64 {
+        self.rows[3][3]
+    }
+
+    /// T^{0i} / c = E × B
+    pub fn stress_energy_to_momentum_density(&self, c: f64) -> Vector3 {
+        Vector3::new(self.rows[3][0], self.rows[3][1], self.rows[3][2]) / c
+    }
+
+    /// Poynting vector, c T^{0i} = c² E × B
+    pub fn stress_energy_to_energy_flux(&self, c: f64) -> Vector3 {
+        Vector3::new(self.rows[3][0], self.rows[3][1], self.rows[3][2]) * c
+    }
+
+    /// Maxwell stress tensor σ_{ij} = -T^{ij}, the momentum flux is T^{ij}
+    pub fn stress_energy_to_maxwell_stress(&self) -> [[f64; 3]; 3] {
+        let mut sigma = [[0.0; 3]; 3];
+        for (sigma, t) in sigma.iter_mut().zip(self.rows.iter()) {
+            for (sigma, t) in sigma.iter_mut().zip(t.iter()) {
+                *sigma = -t;
+            }
+        }
+        sigma
+    }
+
+    /// Principal momentum fluxes, the eigenvalues of T^{ij}, with their axes, largest first
+    ///
+    /// Positive values push outward across a surface normal to the axis (pressure),
+    /// and negative ones pull (tension), as along electric or magnetic field lines.
+    pub fn stress_energy_to_principal_stresses(&self) -> [(f64, Vector3); 3] {
+        let mut t = [[0.0; 3]; 3];
+        for (t, row) in t.iter_mut().zip(self.rows.iter()) {
+            t.copy_from_slice(&row[..3]);
+        }
+        let scale = t.iter().flatten().map(|t| t.abs()).fold(0.0, f64::max);
+        // cyclic Jacobi rotations, v collecting the axes as columns
+        let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        for _ in 0..32 {
+            let off = t[0][1].abs() + t[1][2].abs() + t[0][2].abs();
+            if off <= 1e-15 * scale {
+                break;
+            }
+            for (p, q) in [(0, 1), (1, 2), (0, 2)] {
+                if t[p][q] == 0.0 {
+                    continue;
+                }
+                let theta = (t[q][q] - t[p][p]) / (2.0 * t[p][q]);
+                let tan = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let cos = 1.0 / (tan * tan + 1.0).sqrt();
+                let sin = tan * cos;
+                for row in t.iter_mut().chain(v.iter_mut()) {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = cos * kp - sin * kq;
+                    row[q] = sin * kp + cos * kq;
+                }
+                let (tp, tq) = (t[p], t[q]);
+                for k in 0..3 {
+                    t[p][k] = cos * tp[k] - sin * tq[k];
+                    t[q][k] = sin * tp[k] + cos * tq[k];
+                }
+            }
+        }
+        let mut principal = [0, 1, 2].map(|k| (t[k][k], Vector3::new(v[0][k], v[1][k], v[2][k])));
+        principal.sort_by(|a, b| b.0.total_cmp(&a.0));
+        principal
+    }
+}
+
+/// Power radiated by a charge, by Liénard's generalization of the Larmor formula
+///
+/// P = q² c³ a_μ a^μ / 6π with the inputs of `Matrix::field_strength` and ε = 1,
+/// in the units of the energy flux of `Matrix::stress_energy`.
+/// This is the energy per time of the charge (emission time), and is Lorentz invariant.
+pub fn lienard_power(q: f64, u: Vector3, a: Vector3, c: f64) -> f64 {
+    let a_t = a.dot(u) / u.gamma();
+    q * q * c * c * c * (a.magnitude2() - a_t * a_t) / 6.0 / std::f64::consts::PI
+}
+
+/// Angular distribution dP/dΩ of `lienard_power` toward the unit vector n
+/// from the charge, per emission time
+///
+/// dP/dΩ = q² c |n × ((n - β) × dβ/dt)|² / (16π² (1 - n·β)⁵).
+/// Divide by 1 - n·β for the power per observer time, which is the Poynting flux
+/// through a sphere around the emission point.
+pub fn radiation_pattern(q: f64, u: Vector3, a: Vector3, n: Vector3, c: f64) -> f64 {
+    let gamma = u.gamma();
+    let a_t = a.dot(u) / gamma;
+    let beta = u / gamma;
+    // dβ/dt = c dβ/ds / γ
+    let beta_dot = (a * gamma - u * a_t) * (c / gamma.powi(3));
+    let k = 1.0 - n.dot(beta);
+    q * q * c * n.cross((n - beta).cross(beta_dot)).magnitude2()
+        / (16.0 * std::f64::consts::PI * std::f64::consts::PI * k.powi(5))
+}
+
+/// Proper length s_0 = cτ_0 = q²/6πεmc² over which a charge radiates away its own field energy
+///
+/// q: charge over εc, as `Matrix::field_strength` takes it
+/// q_over_m: as `PhaseSpace::tick_in_field` takes it
+pub fn radiation_reaction_length(q: f64, q_over_m: f64) -> f64 {
+    q * q_over_m / 6.0 / std::f64::consts::PI
+}
+
+/// Abraham–Lorentz–Dirac radiation reaction over mass, s_0 (j + (j·u) u),
+/// the part of s_0 j orthogonal to u
+///
+/// jerk: j = d²u/ds², or its reduced-order estimate from the Lorentz force.
+/// As j·u = -a·a, the time part is s_0 (d²γ/ds² - γ a·a), whose second term is the energy
+/// `lienard_power` radiates and whose first is a total derivative.
+pub fn abraham_lorentz_dirac_force(jerk: Vector4, u: Vector4, s0: f64) -> Vector4 {
+    (jerk + u * jerk.lorentz_dot(u)) * s0
+}
+
+/// Power radiated by a point dipole at rest, 2nd derivative of the moment by cτ
+///
+/// P = c³ |d²p/ds²|² / 6π for either kind of `DipoleKind` with its normalization,
+/// in the units of `lienard_power`.
+pub fn dipole_power(moment: [Vector3; 3], c: f64) -> f64 {
+    c * c * c * moment[2].magnitude2() / 6.0 / std::f64::consts::PI
+}
+
+/// Lorentz invariant class of an electromagnetic field at a point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldClass {
+    /// E² > c²B², there is a frame with pure electric field if E·B = 0
+    ElectricLike,
+    /// E² < c²B², there is a frame with pure magnetic field if E·B = 0
+    MagneticLike,
+    /// E² = c²B² and E·B = 0, such as radiation
+    Null,
+}
+
+impl Vector4 {
+    /// Calculate Liénard–Wiechert 4-potential with upper index, A^μ = (A, φ / c)
+    ///
+    /// Same inputs as `Matrix::field_strength`, whose result is its exterior derivative.
+    /// A^μ = q u^μ / (4π |l| (u^0 + l·u / |l|)) in Lorenz gauge.
+    pub fn four_potential(q: f64, l: Vector3, u: Vector3) -> Vector4 {
+        let l_len = l.magnitude();
+        if l_len < f64::EPSILON * 2.0 {
+            // too near
+            return Vector4::zero();
+        }
+        let u_t = u.gamma();
+        let d = u_t + l.dot(u) / l_len;
+        Vector4::from_ctv(u_t, u) * (q / l_len / d / 4.0 / std::f64::consts::PI)
+    }
+
+    /// Calculate advanced 4-potential with upper index
+    ///
+    /// Same as `four_potential` but l and u are of the charge on observer's FLC.
+    /// Time reversal keeps φ and flips A.
+    pub fn advanced_four_potential(q: f64, l: Vector3, u: Vector3) -> Vector4 {
+        let p = Vector4::four_potential(q, l, -u);
+        Vector4::from_ctv(p.ct, -p.spatial())
+    }
+
+    /// Calculate 4-potential of a point dipole with upper index in Lorenz gauge
+    ///
+    /// Same inputs as `Matrix::dipole_field_strength`, whose result is its exterior derivative;
+    /// the jerk is not needed.
+    pub fn dipole_four_potential(
+        kind: DipoleKind,
+        moment: [Vector3; 3],
+        l: Vector3,
+        u: Vector3,
+        a: Vector3,
+    ) -> Vector4 {
+        let Some(source) = DipoleSource::new(kind, moment, l, u, a, Vector3::zero()) else {
+            // too near
+            return Vector4::zero();
+        };
+        let DipoleSource {
+            q: [q, dq, _],
+            u,
+            k,
+            rho,
+            m,
+            ..
+        } = source;
+        let v = contract(dq, k) * rho.recip()
+            + contract(q, u) * rho.powi(-2)
+            + contract(q, k) * ((1.0 + rho * m) * rho.powi(-2));
+        v * (-0.25 / std::f64::consts::PI)
+    }
+
+    /// `dipole_four_potential` of the advanced field
+    ///
+    /// Same inputs as `Matrix::advanced_dipole_field_strength_parts` without the jerk.
+    pub fn advanced_dipole_four_potential(
+        kind: DipoleKind,
+        moment: [Vector3; 3],
+        l: Vector3,
+        u: Vector3,
+        a: Vector3,
+    ) -> Vector4 {
+        let reversed = reverse_dipole_moment(kind, moment);
+        let potential = Vector4::dipole_four_potential(kind, reversed, l, -u, a);
+        Vector4::from_ctv(potential.ct, -potential.spatial())
+    }
+
+    pub fn four_potential_to_scalar_potential(&self, c: f64) -> f64 {
+        self.ct * c
+    }
+
+    pub fn four_potential_to_vector_potential(&self) -> Vector3 {
+        self.spatial()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
+    use rand::Rng;
+    use rand_pcg::Mcg128Xsl64;
 
     use super::*;
+    use crate::{
+        CircularWorldLine, HyperbolicWorldLine, InertialWorldLine, LineOscillateWorldLine,
+        ProperTime, RadiationReaction, WorldLine,
+    };
 
     #[test]
     fn static_charge() {
@@ -82,4 +688,860 @@ mod tests {
         assert_relative_eq!(e.dot(m), 0.0);
         assert_relative_eq!((-l.normalized()).cross(e), m);
     }
+
+    #[test]
+    fn doppler_factor_matches_boosted_coulomb() {
+        // a uniformly moving charge with velocity along the line of sight, whose field
+        // was wrong while the retardation factor was u^0 instead of u^0 + l̂·u
+        let p = Vector3::new(3.0, 4.0, 5.0);
+        let u = Vector3::new(0.4, 0.9, 0.7);
+        let v = u / u.gamma();
+        // observer at the origin, charge at p + v t, on the PLC at t < 0
+        let (a, b, c) = (v.magnitude2() - 1.0, p.dot(v), p.magnitude2());
+        let t = (-b + (b * b - a * c).sqrt()) / a;
+        let charge = Vector4::from_ctv(t, p + v * t);
+        let fs = Matrix::field_strength(2.0, charge.spatial(), u, Vector3::zero());
+        // Coulomb field in the rest frame of the charge, boosted back
+        let r = (Matrix::lorentz(u) * (Vector4::zero() - charge)).spatial();
+        let rest = Matrix::field_strength(2.0, -r, Vector3::zero(), Vector3::zero());
+        let boosted = Matrix::lorentz(-u) * rest * Matrix::lorentz(-u).transposed();
+        assert_relative_eq!(
+            fs.field_strength_to_electric_field(1.0),
+            boosted.field_strength_to_electric_field(1.0),
+            epsilon = 1e-15,
+        );
+        assert_relative_eq!(
+            fs.field_strength_to_magnetic_field(),
+            boosted.field_strength_to_magnetic_field(),
+            epsilon = 1e-15,
+        );
+    }
+
+    #[test]
+    fn uniform_motion_advanced_equals_retarded() {
+        // observer at the origin, charge at p + v t
+        let p = Vector3::new(3.0, 4.0, 5.0);
+        let v = Vector3::new(0.3, -0.2, 0.1);
+        let (a, b, c) = (v.magnitude2() - 1.0, p.dot(v), p.magnitude2());
+        let d = (b * b - a * c).sqrt();
+        let (t_ret, t_adv) = ((-b + d) / a, (-b - d) / a);
+        assert!(t_ret < 0.0 && t_adv > 0.0);
+        let u = v / (1.0 - v.magnitude2()).sqrt();
+        let ret = Matrix::field_strength(2.0, p + v * t_ret, u, Vector3::zero());
+        let adv = Matrix::advanced_field_strength(2.0, p + v * t_adv, u, Vector3::zero());
+        // Heaviside's field of a uniformly moving charge, from its present position
+        let gamma2 = 1.0 / (1.0 - v.magnitude2());
+        let r2 = p.magnitude2() - v.cross(p).magnitude2();
+        assert_relative_eq!(
+            ret.field_strength_to_electric_field(1.0),
+            -p * (2.0 / 4.0 / std::f64::consts::PI / gamma2 / r2.powf(1.5)),
+            epsilon = 1e-15,
+        );
+        assert_relative_eq!(
+            ret.field_strength_to_electric_field(1.0),
+            adv.field_strength_to_electric_field(1.0),
+            epsilon = 1e-15,
+        );
+        assert_relative_eq!(
+            ret.field_strength_to_magnetic_field(),
+            adv.field_strength_to_magnetic_field(),
+            epsilon = 1e-15,
+        );
+    }
+
+    #[test]
+    fn field_strength_is_exterior_derivative_of_potential() {
+        use crate::{CircularWorldLine, WorldLine};
+
+        let wl = CircularWorldLine::helical(
+            Vector3::new(0.5, -0.3, 0.2),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.1, 0.2, 1.0),
+            0.1,
+            0.2,
+            1.0,
+        )
+        .unwrap();
+        let q = 2.0;
+        let potential = |x: Vector4| {
+            let (y, u, _) = wl.past_intersection(1.0, x).unwrap();
+            Vector4::four_potential(q, (y - x).spatial(), u)
+        };
+        let x = Vector4::new(3.0, 4.0, -2.0, 10.0);
+        let h = 1e-5;
+        let basis = [
+            Vector4::new(1.0, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, 1.0, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 1.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ];
+        // d[m][n] = ∂_m A^n
+        let d = basis.map(|e| {
+            let da = (potential(x + e * h) - potential(x - e * h)) / (2.0 * h);
+            [da.x, da.y, da.z, da.ct]
+        });
+        let eta = |m: usize| if m == 3 { -1.0 } else { 1.0 };
+        let (y, u, a) = wl.past_intersection(1.0, x).unwrap();
+        let fs = Matrix::field_strength(q, (y - x).spatial(), u, a);
+        for (m, row) in fs.rows.iter().enumerate() {
+            for (n, &f) in row.iter().enumerate() {
+                // F^{mn} = ∂^m A^n - ∂^n A^m
+                assert_relative_eq!(f, eta(m) * d[m][n] - eta(n) * d[n][m], epsilon = 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn static_potential() {
+        let l = Vector3::new(3.0, 4.0, 5.0);
+        let p = Vector4::four_potential(2.0, l, Vector3::zero());
+        assert_relative_eq!(
+            p.four_potential_to_scalar_potential(3.0),
+            3.0 * 2.0 / l.magnitude() / 4.0 / std::f64::consts::PI
+        );
+        assert_relative_eq!(p.four_potential_to_vector_potential(), Vector3::zero());
+        let u = Vector3::new(0.3, -0.2, 0.1);
+        let p = Vector4::four_potential(2.0, l, u);
+        assert_relative_eq!(p.four_potential_to_vector_potential(), u / u.gamma() * p.ct);
+        let adv = Vector4::advanced_four_potential(2.0, l, -u);
+        assert_relative_eq!(adv.ct, Vector4::four_potential(2.0, l, u).ct);
+    }
+
+    #[test]
+    fn hodge_dual() {
+        // ε^{μνρσ} with ε^{txyz} = 1, indices ordered as x, y, z, t
+        let epsilon = |i: [usize; 4]| {
+            let mut sign = -1.0;
+            for a in 0..4 {
+                for b in a + 1..4 {
+                    if i[a] == i[b] {
+                        return 0.0;
+                    }
+                    if i[a] > i[b] {
+                        sign = -sign;
+                    }
+                }
+            }
+            sign
+        };
+        let c = 2.0;
+        let mut rng = Mcg128Xsl64::new(1);
+        let mut random = || {
+            Vector3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            )
+        };
+        for _ in 0..100 {
+            let fs = Matrix::field_strength(2.0, random() * 5.0, random(), random());
+            let lower = Matrix::eta() * fs * Matrix::eta();
+            let dual = fs.hodge_dual();
+            for m in 0..4 {
+                for n in 0..4 {
+                    let mut expected = 0.0;
+                    for r in 0..4 {
+                        for s in 0..4 {
+                            expected += 0.5 * epsilon([m, n, r, s]) * lower.rows[r][s];
+                        }
+                    }
+                    assert_relative_eq!(dual.rows[m][n], expected, epsilon = 1e-12);
+                }
+            }
+            assert_eq!(dual.hodge_dual(), fs * -1.0);
+            assert_relative_eq!(
+                dual.field_strength_to_magnetic_field(),
+                fs.field_strength_to_electric_field(c) / -c,
+                epsilon = 1e-12
+            );
+            // commutes with boosts
+            let boost = Matrix::lorentz(random());
+            let (a, b) = (
+                (boost * fs * boost.transposed()).hodge_dual(),
+                boost * dual * boost.transposed(),
+            );
+            for (a, b) in a.rows.iter().zip(b.rows.iter()) {
+                for (a, b) in a.iter().zip(b.iter()) {
+                    assert_relative_eq!(a, b, epsilon = 1e-10);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lorentz_four_force() {
+        let c = 2.0;
+        let (q, m) = (3.0, 0.5);
+        let mut rng = Mcg128Xsl64::new(1);
+        let mut random = || {
+            Vector3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            )
+        };
+        for _ in 0..100 {
+            let fs = Matrix::field_strength(2.0, random() * 5.0, random(), random());
+            let (e, b) = (
+                fs.field_strength_to_electric_field(c),
+                fs.field_strength_to_magnetic_field(),
+            );
+            let u = Vector4::from_velocity(random());
+            let force = fs.lorentz_four_force(q, m, u);
+            assert_relative_eq!(
+                force.spatial(),
+                (e * (u.ct / c) + u.spatial().cross(b)) * (q / m),
+                epsilon = 1e-12
+            );
+            assert_relative_eq!(force.ct, e.dot(u.spatial()) / c * (q / m), epsilon = 1e-12);
+            assert_relative_eq!(force.lorentz_dot(u), 0.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn field_strength_invariants() {
+        let mut rng = Mcg128Xsl64::new(1);
+        let mut random = || {
+            Vector3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            )
+        };
+        let c = 2.0;
+        for _ in 0..100 {
+            let fs = Matrix::field_strength(2.0, random() * 5.0, random(), random());
+            let class = fs.classify_field_strength(c, 0.0);
+            let u = fs.parallel_frame_velocity(c).unwrap();
+            let boost = Matrix::lorentz(u);
+            let boosted = boost * fs * boost.transposed();
+            let (e, b) = (
+                boosted.field_strength_to_electric_field(c),
+                boosted.field_strength_to_magnetic_field(),
+            );
+            let scale = fs.field_strength_to_electric_field(c).magnitude2()
+                + fs.field_strength_to_magnetic_field().magnitude2() * c * c;
+            assert_relative_eq!(e.cross(b).magnitude(), 0.0, epsilon = 1e-12 * scale);
+            assert_relative_eq!(
+                boosted.field_strength_invariant(c),
+                fs.field_strength_invariant(c),
+                epsilon = 1e-12 * scale
+            );
+            assert_relative_eq!(
+                boosted.field_strength_pseudo_invariant(c),
+                fs.field_strength_pseudo_invariant(c),
+                epsilon = 1e-12 * scale
+            );
+            assert_eq!(boosted.classify_field_strength(c, 0.0), class);
+        }
+
+        // the field of a single charge has E·B = 0, so the parallel frame is its rest frame
+        let fs =
+            Matrix::field_strength(2.0, Vector3::new(3.0, 4.0, 5.0), random(), Vector3::zero());
+        assert_eq!(
+            fs.classify_field_strength(c, 1e-3),
+            FieldClass::ElectricLike
+        );
+        let u = fs.parallel_frame_velocity(c).unwrap();
+        let boost = Matrix::lorentz(u);
+        let boosted = boost * fs * boost.transposed();
+        assert_relative_eq!(
+            boosted.field_strength_to_magnetic_field(),
+            Vector3::zero(),
+            epsilon = 1e-12
+        );
+
+        // far from an accelerated charge
+        let a = Vector3::new(0.0, 0.0, 1.0);
+        let fs = Matrix::field_strength(2.0, Vector3::new(1e4, 0.0, 0.0), Vector3::zero(), a);
+        assert_eq!(fs.classify_field_strength(1.0, 1e-3), FieldClass::Null);
+
+        // plane wave along x, E = (0, 0, 1), B = (0, -1, 0)
+        let fs = Matrix::new(
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [-1.0, 0.0, 0.0, -1.0],
+            [0.0, 0.0, 1.0, 0.0],
+        );
+        assert_eq!(fs.classify_field_strength(1.0, 0.0), FieldClass::Null);
+        assert_eq!(fs.parallel_frame_velocity(1.0), None);
+    }
+
+    #[test]
+    fn stress_energy() {
+        let mut rng = Mcg128Xsl64::new(2);
+        let mut random = || {
+            Vector3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            )
+        };
+        let c = 2.0;
+        for _ in 0..100 {
+            let fs = Matrix::field_strength(2.0, random() * 5.0, random(), random());
+            let e = fs.field_strength_to_electric_field(c);
+            let b = fs.field_strength_to_magnetic_field();
+            let t = Matrix::stress_energy(fs, c);
+            let scale = e.magnitude2() + b.magnitude2() * c * c;
+            assert_relative_eq!(t.stress_energy_to_energy_density(), 0.5 * scale);
+            assert_relative_eq!(t.stress_energy_to_momentum_density(c), e.cross(b));
+            assert_relative_eq!(t.stress_energy_to_energy_flux(c), e.cross(b) * c * c);
+            let e = [e.x, e.y, e.z];
+            let b = [b.x, b.y, b.z];
+            for (i, sigma) in t.stress_energy_to_maxwell_stress().iter().enumerate() {
+                for (j, &sigma) in sigma.iter().enumerate() {
+                    let delta = if i == j { 0.5 * scale } else { 0.0 };
+                    let expected = e[i] * e[j] + b[i] * b[j] * c * c - delta;
+                    assert_relative_eq!(sigma, expected, epsilon = 1e-12 * scale);
+                }
+            }
+            // traceless
+            let trace = (0..4).map(|i| t.rows[i][i]).sum::<f64>() - 2.0 * t.rows[3][3];
+            assert_relative_eq!(trace, 0.0, epsilon = 1e-12 * scale);
+
+            // a tensor under boosts
+            let boost = Matrix::lorentz(random());
+            let expected = boost * t * boost.transposed();
+            let actual = Matrix::stress_energy(boost * fs * boost.transposed(), c);
+            for (expected, actual) in expected.rows.iter().zip(actual.rows.iter()) {
+                for (expected, actual) in expected.iter().zip(actual.iter()) {
+                    assert_relative_eq!(expected, actual, epsilon = 1e-12 * scale);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn principal_stresses() {
+        let mut rng = Mcg128Xsl64::new(3);
+        let mut random = || {
+            Vector3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            )
+        };
+        let c = 2.0;
+        for _ in 0..100 {
+            let (e, b) = (random(), random());
+            let fs = Matrix::from_electric_magnetic(e / c, b);
+            let t = Matrix::stress_energy(fs, c);
+            let principal = t.stress_energy_to_principal_stresses();
+            // orthonormal axes diagonalizing T^{ij}, sorted
+            for (i, &(lambda, axis)) in principal.iter().enumerate() {
+                assert_relative_eq!(axis.magnitude(), 1.0, epsilon = 1e-12);
+                for &(_, other) in &principal[i + 1..] {
+                    assert_relative_eq!(axis.dot(other), 0.0, epsilon = 1e-12);
+                }
+                let row = |r: &[f64; 4]| Vector3::new(r[0], r[1], r[2]);
+                let t_axis = Vector3::new(
+                    row(&t.rows[0]).dot(axis),
+                    row(&t.rows[1]).dot(axis),
+                    row(&t.rows[2]).dot(axis),
+                );
+                assert_relative_eq!(t_axis, axis * lambda, epsilon = 1e-12);
+            }
+            assert!(principal[0].0 >= principal[1].0 && principal[1].0 >= principal[2].0);
+            // the energy density pushes along E × B, and the other two are ±√(I² + (2c E·B)²)/2
+            let energy = t.stress_energy_to_energy_density();
+            let i = fs.field_strength_invariant(c);
+            let k = 0.5 * (i * i + (2.0 * c * e.dot(b)).powi(2)).sqrt();
+            assert_relative_eq!(principal[0].0, energy, epsilon = 1e-12);
+            assert_relative_eq!(
+                principal[0].1.cross(e.cross(b)).magnitude(),
+                0.0,
+                epsilon = 1e-12
+            );
+            assert_relative_eq!(principal[1].0, k, epsilon = 1e-12);
+            assert_relative_eq!(principal[2].0, -k, epsilon = 1e-12);
+        }
+        // a pure electric field pulls along its field lines and pushes across them
+        let e = Vector3::new(0.0, 3.0, 4.0);
+        let t = Matrix::stress_energy(Matrix::from_electric_magnetic(e / c, Vector3::zero()), c);
+        let [(p0, _), (p1, _), (tension, axis)] = t.stress_energy_to_principal_stresses();
+        assert_relative_eq!(p0, 12.5, epsilon = 1e-12);
+        assert_relative_eq!(p1, 12.5, epsilon = 1e-12);
+        assert_relative_eq!(tension, -12.5, epsilon = 1e-12);
+        assert_relative_eq!(axis.cross(e).magnitude(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn field_strength_parts() {
+        let l = Vector3::new(3.0, 4.0, 5.0);
+        let u = Vector3::new(0.1, 0.2, -0.05);
+        let a = Vector3::new(0.01, 0.02, 0.03);
+        let (near, radiation) = Matrix::field_strength_parts(2.0, l, u, a);
+        let fs = Matrix::field_strength(2.0, l, u, a);
+        for (fs, (near, radiation)) in fs.rows.iter().flatten().zip(
+            near.rows
+                .iter()
+                .flatten()
+                .zip(radiation.rows.iter().flatten()),
+        ) {
+            assert_relative_eq!(*fs, near + radiation, epsilon = 1e-15);
+        }
+        // near part does not depend on a
+        let (near_0, radiation_0) = Matrix::field_strength_parts(2.0, l, u, Vector3::zero());
+        assert_eq!(near, near_0);
+        assert_eq!(radiation_0, Matrix::zero());
+
+        // falloff: scale l by k with the same direction
+        let k = 10.0;
+        let (far_near, far_radiation) = Matrix::field_strength_parts(2.0, l * k, u, a);
+        let e = |f: Matrix| f.field_strength_to_electric_field(1.0);
+        assert_relative_eq!(e(far_near) * (k * k), e(near), epsilon = 1e-15);
+        assert_relative_eq!(e(far_radiation) * k, e(radiation), epsilon = 1e-15);
+        // radiation is transverse to l, with B = l̂ × E / c pointing away from the charge
+        assert_relative_eq!(e(radiation).dot(l), 0.0, epsilon = 1e-15);
+        assert_relative_eq!(
+            radiation.field_strength_to_magnetic_field(),
+            (-l.normalized()).cross(e(radiation)),
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    fn radiated_power() {
+        let q = 2.0;
+        let c: f64 = 2.0;
+        let u = Vector3::new(0.5, -0.3, 0.2);
+        let a = Vector3::new(0.1, 0.2, -0.3);
+        // a non-relativistic charge follows Larmor's formula, q² |dv/dt|² / 6πc³ with ε = 1
+        let slow = Vector3::new(1e-9, 0.0, 0.0);
+        let (charge, dv_dt): (f64, Vector3) = (q * c, a * c * c);
+        let larmor = charge.powi(2) * dv_dt.magnitude2() / (6.0 * std::f64::consts::PI * c.powi(3));
+        assert_relative_eq!(lienard_power(q, slow, a, c), larmor, max_relative = 1e-9);
+        // invariant under boosts
+        let boost = Matrix::lorentz(Vector3::new(-0.4, 0.1, 0.7));
+        let u4 = boost * Vector4::from_velocity(u);
+        let a4 = boost * Vector4::from_ctv(a.dot(u) / u.gamma(), a);
+        assert_relative_eq!(
+            lienard_power(q, u4.spatial(), a4.spatial(), c),
+            lienard_power(q, u, a, c),
+            max_relative = 1e-12
+        );
+
+        // integrate the Poynting flux of the radiation field over a large sphere
+        // around the emission point, weighted by 1 - n·β to get per emission time
+        let r = 1e3;
+        let beta = u / u.gamma();
+        let (n_theta, n_phi) = (200, 400);
+        let mut total = 0.0;
+        let mut pattern = 0.0;
+        for i in 0..n_theta {
+            let theta = (i as f64 + 0.5) * std::f64::consts::PI / n_theta as f64;
+            for j in 0..n_phi {
+                let phi = (j as f64 + 0.5) * std::f64::consts::TAU / n_phi as f64;
+                let n = Vector3::new(
+                    theta.sin() * phi.cos(),
+                    theta.sin() * phi.sin(),
+                    theta.cos(),
+                );
+                let d_omega = theta.sin() * std::f64::consts::PI / n_theta as f64
+                    * std::f64::consts::TAU
+                    / n_phi as f64;
+                let (_, radiation) = Matrix::field_strength_parts(q, -n * r, u, a);
+                let flux = Matrix::stress_energy(radiation, c).stress_energy_to_energy_flux(c);
+                let dp = flux.dot(n) * r * r * (1.0 - n.dot(beta));
+                assert_relative_eq!(
+                    dp,
+                    radiation_pattern(q, u, a, n, c),
+                    max_relative = 1e-9,
+                    epsilon = 1e-15
+                );
+                total += dp * d_omega;
+                pattern += radiation_pattern(q, u, a, n, c) * d_omega;
+            }
+        }
+        assert_relative_eq!(total, lienard_power(q, u, a, c), max_relative = 1e-3);
+        assert_relative_eq!(pattern, total, max_relative = 1e-9);
+    }
+
+    #[test]
+    fn dipole_field_strength() {
+        let c = 2.0;
+        // a pair of opposite charges moving with u, separated by d in their rest frame
+        let (q, d) = (3.0, Vector3::new(1e-3, -2e-3, 1e-3));
+        let u = Vector3::new(0.3, -0.5, 0.2);
+        let to_world = Matrix::lorentz(-u);
+        let origin = Vector4::new(1.0, 2.0, -1.0, 0.5);
+        let pair = [
+            (
+                q,
+                InertialWorldLine::new(origin + to_world * Vector4::from_ctv(0.0, d * 0.5), u),
+            ),
+            (
+                -q,
+                InertialWorldLine::new(origin - to_world * Vector4::from_ctv(0.0, d * 0.5), u),
+            ),
+        ];
+        let dipole = InertialWorldLine::new(origin, u);
+        let mut rng = Mcg128Xsl64::new(1);
+        for _ in 0..100 {
+            let x = Vector4::new(
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(10.0..20.0),
+            );
+            let mut expected = Matrix::zero();
+            for (q, wl) in pair.iter() {
+                let (y, u, a) = wl.past_intersection(c, x).unwrap();
+                expected = expected + Matrix::field_strength(*q, (y - x).spatial(), u, a);
+            }
+            let (y, u, _) = dipole.past_intersection(c, x).unwrap();
+            let moment = [d * q, Vector3::zero(), Vector3::zero()];
+            let fs = Matrix::dipole_field_strength(
+                DipoleKind::Electric,
+                moment,
+                (y - x).spatial(),
+                u,
+                Vector3::zero(),
+                Vector3::zero(),
+            );
+            for (f, e) in fs.rows.iter().zip(expected.rows.iter()) {
+                for (f, e) in f.iter().zip(e.iter()) {
+                    assert_relative_eq!(f, e, epsilon = 1e-6 * q * d.magnitude());
+                }
+            }
+        }
+
+        // rigid pairs keep their separation on Fermi–Walker transported axes, so accelerated
+        // ones are dipoles of constant moment: a pair in hyperbolic motion, whose leading
+        // charge accelerates less, and a pair circling one above the other
+        let alpha = 0.1;
+        let hyperbolic = |d: Vector3| {
+            let a = Vector3::Y_AXIS / (1.0 / alpha + d.y);
+            HyperbolicWorldLine::new(origin + Vector4::from_ctv(0.0, d), a).unwrap()
+        };
+        let circular = |z: f64| {
+            let center = origin.spatial() + Vector3::Z_AXIS * z;
+            let radius = Vector3::new(2.0, 0.0, 0.0);
+            CircularWorldLine::new(center, radius, Vector3::Z_AXIS, 0.05, c).unwrap()
+        };
+        let (d_circular, c_circular) = (Vector3::Z_AXIS * 2e-3, circular(0.0));
+        let (d_hyperbolic, h_hyperbolic) = (d, hyperbolic(Vector3::zero()));
+        let (h_pair, c_pair) = (
+            [hyperbolic(d * 0.5), hyperbolic(-d * 0.5)],
+            [circular(1e-3), circular(-1e-3)],
+        );
+        let accelerated: [(Vector3, [&dyn ProperTime; 2], &dyn ProperTime); 2] = [
+            (d_hyperbolic, [&h_pair[0], &h_pair[1]], &h_hyperbolic),
+            (d_circular, [&c_pair[0], &c_pair[1]], &c_circular),
+        ];
+        for (d, pair, dipole) in accelerated {
+            let mut checked = 0;
+            for _ in 0..100 {
+                let x = Vector4::new(
+                    rng.gen_range(-10.0..10.0),
+                    rng.gen_range(-10.0..10.0),
+                    rng.gen_range(-10.0..10.0),
+                    rng.gen_range(10.0..20.0),
+                );
+                let Ok((y, u, a)) = dipole.past_intersection(c, x) else {
+                    // beyond the horizon of the hyperbolic motion
+                    continue;
+                };
+                let mut expected = Matrix::zero();
+                for (q, wl) in [q, -q].into_iter().zip(pair) {
+                    let (y, u, a) = wl.past_intersection(c, x).unwrap();
+                    expected = expected + Matrix::field_strength(q, (y - x).spatial(), u, a);
+                }
+                let jerk = dipole.jerk(c, y, 1e-2).unwrap();
+                let moment = [d * q, Vector3::zero(), Vector3::zero()];
+                let fs = Matrix::dipole_field_strength(
+                    DipoleKind::Electric,
+                    moment,
+                    (y - x).spatial(),
+                    u,
+                    a,
+                    jerk,
+                );
+                let scale = expected
+                    .rows
+                    .iter()
+                    .flatten()
+                    .fold(0.0_f64, |m, f| m.max(f.abs()));
+                for (f, e) in fs.rows.iter().zip(expected.rows.iter()) {
+                    for (f, e) in f.iter().zip(e.iter()) {
+                        assert_relative_eq!(f, e, epsilon = 1e-3 * scale);
+                    }
+                }
+                checked += 1;
+            }
+            assert!(checked >= 50, "{checked}");
+        }
+
+        // opposite charges oscillating against each other are a Hertzian dipole
+        let (amp, frequency) = (Vector3::new(1e-6, 2e-6, -1e-6), 0.3);
+        let k = frequency * std::f64::consts::TAU / c;
+        let pair = [
+            (
+                q,
+                LineOscillateWorldLine::new(Vector3::zero(), amp, frequency, c).unwrap(),
+            ),
+            (
+                -q,
+                LineOscillateWorldLine::new(Vector3::zero(), -amp, frequency, c).unwrap(),
+            ),
+        ];
+        for _ in 0..100 {
+            let x = Vector4::new(
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(10.0..20.0),
+            );
+            let mut expected = Matrix::zero();
+            for (q, wl) in pair.iter() {
+                let (y, u, a) = wl.past_intersection(c, x).unwrap();
+                expected = expected + Matrix::field_strength(*q, (y - x).spatial(), u, a);
+            }
+            let (sin, cos) = (k * (x.ct - x.spatial().magnitude())).sin_cos();
+            let p = amp * (2.0 * q);
+            let moment = [p * sin, p * (k * cos), p * (-k * k * sin)];
+            let fs = Matrix::dipole_field_strength(
+                DipoleKind::Electric,
+                moment,
+                -x.spatial(),
+                Vector3::zero(),
+                Vector3::zero(),
+                Vector3::zero(),
+            );
+            for (f, e) in fs.rows.iter().zip(expected.rows.iter()) {
+                for (f, e) in f.iter().zip(e.iter()) {
+                    assert_relative_eq!(f, e, epsilon = 1e-6 * p.magnitude());
+                }
+            }
+        }
+
+        // a slow ring of n charges is a magnetic dipole of q n u R / 2 over εc²
+        let (n, radius, speed) = (64, 1e-2, 1e-7);
+        let q_ring = 5.0;
+        let m = Vector3::Z_AXIS * (q_ring * n as f64 * speed * radius / 2.0);
+        for _ in 0..100 {
+            let x = Vector3::new(
+                rng.gen_range(-2.0..2.0),
+                rng.gen_range(-2.0..2.0),
+                rng.gen_range(-2.0..2.0),
+            );
+            if x.magnitude() < 1.0 {
+                continue;
+            }
+            let mut expected = Vector3::zero();
+            for i in 0..n {
+                let phi = i as f64 * std::f64::consts::TAU / n as f64;
+                let (sin, cos) = phi.sin_cos();
+                let y = Vector3::new(cos, sin, 0.0) * radius;
+                let u = Vector3::new(-sin, cos, 0.0) * speed;
+                let a = Vector3::new(cos, sin, 0.0) * (-speed * speed / radius);
+                expected +=
+                    Matrix::field_strength(q_ring, y - x, u, a).field_strength_to_magnetic_field();
+            }
+            let moment = [m, Vector3::zero(), Vector3::zero()];
+            let fs = Matrix::dipole_field_strength(
+                DipoleKind::Magnetic,
+                moment,
+                -x,
+                Vector3::zero(),
+                Vector3::zero(),
+                Vector3::zero(),
+            );
+            assert_relative_eq!(
+                fs.field_strength_to_magnetic_field(),
+                expected,
+                max_relative = 1e-3,
+                epsilon = 1e-3 * expected.magnitude()
+            );
+        }
+
+        // a Hertzian dipole radiates c³|d²p/ds²|² / 6π, as a charge does by Larmor's formula
+        let ddp = Vector3::new(0.3, -0.1, 0.2);
+        let moment = [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-0.5, 0.4, 0.1),
+            ddp,
+        ];
+        let r = 1e3;
+        let (n_theta, n_phi) = (200, 400);
+        for kind in [DipoleKind::Electric, DipoleKind::Magnetic] {
+            let mut total = 0.0;
+            for i in 0..n_theta {
+                let theta = (i as f64 + 0.5) * std::f64::consts::PI / n_theta as f64;
+                for j in 0..n_phi {
+                    let phi = (j as f64 + 0.5) * std::f64::consts::TAU / n_phi as f64;
+                    let n = Vector3::new(
+                        theta.sin() * phi.cos(),
+                        theta.sin() * phi.sin(),
+                        theta.cos(),
+                    );
+                    let d_omega = theta.sin() * std::f64::consts::PI / n_theta as f64
+                        * std::f64::consts::TAU
+                        / n_phi as f64;
+                    let (_, radiation) = Matrix::dipole_field_strength_parts(
+                        kind,
+                        moment,
+                        -n * r,
+                        Vector3::zero(),
+                        Vector3::zero(),
+                        Vector3::zero(),
+                    );
+                    let flux = Matrix::stress_energy(radiation, c).stress_energy_to_energy_flux(c);
+                    total += flux.dot(n) * r * r * d_omega;
+                }
+            }
+            assert_relative_eq!(total, dipole_power(moment, c), max_relative = 1e-3);
+        }
+
+        // advanced field of a static dipole is the retarded one
+        let l = Vector3::new(3.0, -1.0, 2.0);
+        let moment = [
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::zero(),
+            Vector3::zero(),
+        ];
+        for kind in [DipoleKind::Electric, DipoleKind::Magnetic] {
+            let (near, _) = Matrix::dipole_field_strength_parts(
+                kind,
+                moment,
+                l,
+                Vector3::zero(),
+                Vector3::zero(),
+                Vector3::zero(),
+            );
+            let (advanced, _) = Matrix::advanced_dipole_field_strength_parts(
+                kind,
+                moment,
+                l,
+                Vector3::zero(),
+                Vector3::zero(),
+                Vector3::zero(),
+            );
+            assert_eq!(near, advanced);
+        }
+    }
+
+    #[test]
+    fn dipole_field_strength_is_exterior_derivative_of_potential() {
+        let c = 1.0;
+        let world_lines: [Box<dyn ProperTime>; 3] = [
+            Box::new(InertialWorldLine::new(
+                Vector4::new(0.5, -0.3, 0.2, 1.0),
+                Vector3::new(0.3, 0.1, -0.2),
+            )),
+            Box::new(
+                HyperbolicWorldLine::new(
+                    Vector4::new(0.5, -0.3, 0.2, 5.0),
+                    Vector3::new(0.0, 0.1, 0.05),
+                )
+                .unwrap(),
+            ),
+            Box::new(
+                CircularWorldLine::new(
+                    Vector3::new(0.5, -0.3, 0.2),
+                    Vector3::new(1.0, 0.0, 0.0),
+                    Vector3::new(0.1, 0.2, 1.0),
+                    0.1,
+                    c,
+                )
+                .unwrap(),
+            ),
+        ];
+        let (p, k) = (Vector3::new(1.0, -2.0, 0.5), 0.4);
+        let moment = |s: f64| {
+            let (sin, cos) = (k * s).sin_cos();
+            [p * cos, p * (-k * sin), p * (-k * k * cos)]
+        };
+        for (wl, kind) in world_lines
+            .iter()
+            .flat_map(|wl| [(wl, DipoleKind::Electric), (wl, DipoleKind::Magnetic)])
+        {
+            // the moment is fixed on the boost frame, which turns on the circle
+            let retarded = |x: Vector4| {
+                let (y, u, a) = wl.past_intersection(c, x).unwrap();
+                let s = wl.proper_time(c, y.ct).unwrap();
+                let jerk = wl.jerk(c, y, 1e-2).unwrap();
+                let moment = fermi_walker_moment(moment(s), u, a, jerk);
+                ((y - x).spatial(), u, a, jerk, moment)
+            };
+            let potential = |x: Vector4| {
+                let (l, u, a, _, moment) = retarded(x);
+                Vector4::dipole_four_potential(kind, moment, l, u, a)
+            };
+            let x = Vector4::new(3.0, 4.0, -2.0, 10.0);
+            let h = 1e-5;
+            let basis = [
+                Vector4::new(1.0, 0.0, 0.0, 0.0),
+                Vector4::new(0.0, 1.0, 0.0, 0.0),
+                Vector4::new(0.0, 0.0, 1.0, 0.0),
+                Vector4::new(0.0, 0.0, 0.0, 1.0),
+            ];
+            // d[m][n] = ∂_m A^n
+            let d = basis.map(|e| {
+                let da = (potential(x + e * h) - potential(x - e * h)) / (2.0 * h);
+                [da.x, da.y, da.z, da.ct]
+            });
+            let eta = |m: usize| if m == 3 { -1.0 } else { 1.0 };
+            let (l, u, a, jerk, moment) = retarded(x);
+            let fs = Matrix::dipole_field_strength(kind, moment, l, u, a, jerk);
+            for (m, row) in fs.rows.iter().enumerate() {
+                for (n, &f) in row.iter().enumerate() {
+                    // F^{mn} = ∂^m A^n - ∂^n A^m
+                    assert_relative_eq!(f, eta(m) * d[m][n] - eta(n) * d[n][m], epsilon = 1e-8);
+                    eprintln!("ok {kind:?} {m}{n}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn radiation_reaction() {
+        let c = 2.0;
+        let (q, q_over_m) = (0.3, 0.5);
+        let s0 = radiation_reaction_length(q, q_over_m);
+        let fs =
+            Matrix::field_strength_from_fields(Vector3::zero(), Vector3::new(0.0, 0.0, 0.5), c);
+        let ds = 1e-3;
+        // gyrate with each reaction, returning the final γ and ∫ γ s_0 a·a ds
+        let run = |reaction: RadiationReaction| {
+            let mut u = Vector4::from_velocity(Vector3::new(2.0, 0.0, 0.0));
+            let mut last_force = None;
+            let mut radiated = 0.0;
+            for _ in 0..20000 {
+                let force = fs.lorentz_four_force(q_over_m, 1.0, u);
+                // the energy balance of the Liénard power with the mass q / q_over_m
+                let power = lienard_power(q, u.spatial(), force.spatial(), c);
+                assert_relative_eq!(
+                    s0 * force.lorentz_norm2(),
+                    power * q_over_m / q / c.powi(3),
+                    max_relative = 1e-12
+                );
+                radiated += u.ct * s0 * force.lorentz_norm2() * ds;
+                let reaction = match reaction {
+                    RadiationReaction::None => Vector4::zero(),
+                    RadiationReaction::LandauLifshitz => {
+                        fs.landau_lifshitz_force(Matrix::zero(), q_over_m, 1.0, u, s0)
+                    }
+                    RadiationReaction::ReducedAbrahamLorentzDirac => match last_force {
+                        Some(last) => abraham_lorentz_dirac_force((force - last) / ds, u, s0),
+                        None => Vector4::zero(),
+                    },
+                };
+                last_force = Some(force);
+                u = Vector4::from_velocity((u + (force + reaction) * ds).spatial());
+            }
+            (u.ct, radiated)
+        };
+        // Euler steps drift on their own, which the run without reaction measures
+        let (gamma, _) = run(RadiationReaction::None);
+        for reaction in [
+            RadiationReaction::LandauLifshitz,
+            RadiationReaction::ReducedAbrahamLorentzDirac,
+        ] {
+            let (damped, radiated) = run(reaction);
+            assert!(damped < gamma);
+            assert_relative_eq!(gamma - damped, radiated, max_relative = 1e-2);
+        }
+    }
 }
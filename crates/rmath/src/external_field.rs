@@ -0,0 +1,132 @@
+use crate::{Matrix, Vector3, Vector4};
+
+/// Background electromagnetic field given everywhere, not sourced by any world line
+pub trait ExternalField {
+    /// Field strength with upper indices at x, in the units of `Matrix::field_strength`
+    fn field_strength(&self, c: f64, x: Vector4) -> Matrix;
+}
+
+impl<F: Fn(f64, Vector4) -> Matrix> ExternalField for F {
+    fn field_strength(&self, c: f64, x: Vector4) -> Matrix {
+        self(c, x)
+    }
+}
+
+/// Uniform and constant electric and magnetic field
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UniformField {
+    electric: Vector3,
+    magnetic: Vector3,
+}
+
+impl UniformField {
+    pub fn new(electric: Vector3, magnetic: Vector3) -> UniformField {
+        UniformField { electric, magnetic }
+    }
+}
+
+impl ExternalField for UniformField {
+    fn field_strength(&self, c: f64, _x: Vector4) -> Matrix {
+        Matrix::field_strength_from_fields(self.electric, self.magnetic, c)
+    }
+}
+
+/// Linearly polarized plane wave
+///
+/// E = E_0 cos(k (n·x - ct) + φ) and B = n × E / c
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlaneWave {
+    amplitude: Vector3,
+    direction: Vector3,
+    wave_number: f64,
+    phase: f64,
+}
+
+impl PlaneWave {
+    /// amplitude: E_0, whose part along direction is dropped
+    /// direction: n, the direction of propagation
+    /// wave_number: k = 2π / wavelength
+    /// phase: φ at the origin at x_0 = 0
+    pub fn new(amplitude: Vector3, direction: Vector3, wave_number: f64, phase: f64) -> PlaneWave {
+        let direction = direction.normalized();
+        PlaneWave {
+            amplitude: amplitude - direction * amplitude.dot(direction),
+            direction,
+            wave_number,
+            phase,
+        }
+    }
+}
+
+impl ExternalField for PlaneWave {
+    fn field_strength(&self, c: f64, x: Vector4) -> Matrix {
+        let e = self.amplitude
+            * (self.wave_number * (self.direction.dot(x.spatial()) - x.ct) + self.phase).cos();
+        Matrix::field_strength_from_fields(e, self.direction.cross(e) / c, c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{maxwell_residual, FieldClass};
+
+    #[test]
+    fn uniform_field() {
+        let c = 2.0;
+        let (e, b) = (Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, 0.2, -0.1));
+        let fs = UniformField::new(e, b).field_strength(c, Vector4::new(1.0, 2.0, 3.0, 4.0));
+        assert_relative_eq!(fs.field_strength_to_electric_field(c), e);
+        assert_relative_eq!(fs.field_strength_to_magnetic_field(), b);
+    }
+
+    #[test]
+    fn plane_wave() {
+        let c = 2.0;
+        let wave = PlaneWave::new(
+            Vector3::new(1.0, 0.5, 2.0),
+            Vector3::new(0.0, 0.0, 3.0),
+            0.7,
+            0.3,
+        );
+        for i in 0..20 {
+            let x = Vector4::new(1.0, -2.0, 0.1 * i as f64, 0.37 * i as f64);
+            let fs = wave.field_strength(c, x);
+            let e = fs.field_strength_to_electric_field(c);
+            // transverse
+            assert_relative_eq!(e.z, 0.0);
+            assert_relative_eq!(fs.field_strength_invariant(c), 0.0, epsilon = 1e-12);
+            assert_relative_eq!(fs.field_strength_pseudo_invariant(c), 0.0, epsilon = 1e-12);
+            if e.magnitude() > 1e-3 {
+                assert_eq!(fs.classify_field_strength(c, 1e-9), FieldClass::Null);
+            }
+            let residual =
+                maxwell_residual(|x| Ok::<_, ()>(wave.field_strength(c, x)), x, 1e-2).unwrap();
+            assert!(residual.relative() < 1e-6, "{residual:?}");
+        }
+        // it moves at c along n
+        let x = Vector4::new(1.0, -2.0, 0.5, 0.2);
+        let dx = Vector4::new(0.0, 0.0, 1.5, 1.5);
+        assert_eq!(wave.field_strength(c, x), wave.field_strength(c, x + dx));
+    }
+
+    #[test]
+    fn closure_field() {
+        let c = 2.0;
+        let field = |c: f64, x: Vector4| {
+            Matrix::field_strength_from_fields(x.spatial(), Vector3::zero(), c)
+        };
+        let fields: Vec<Box<dyn ExternalField>> = vec![
+            Box::new(field),
+            Box::new(UniformField::new(Vector3::X_AXIS, Vector3::zero())),
+        ];
+        let x = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        let e = fields
+            .iter()
+            .map(|f| f.field_strength(c, x).field_strength_to_electric_field(c))
+            .fold(Vector3::zero(), |sum, e| sum + e);
+        assert_relative_eq!(e, Vector3::new(2.0, 2.0, 3.0));
+    }
+}
@@ -1,13 +1,20 @@
 mod angle;
+mod distribution;
 mod electromagnetism;
+mod external_field;
 mod matrix;
+mod maxwell;
 mod mechanics;
 mod quaternion;
 mod vector;
 mod world_line;
 
 pub use angle::*;
+pub use distribution::*;
+pub use electromagnetism::*;
+pub use external_field::*;
 pub use matrix::*;
+pub use maxwell::*;
 pub use mechanics::*;
 pub use quaternion::*;
 pub use vector::*;
@@ -220,6 +220,58 @@ impl Matrix {
         }
     }
 
+    /// Boost taking covariant velocity u0 to u1 in the plane of their 4-velocities
+    ///
+    /// This carries vectors by Fermi–Walker transport along a step from u0 to u1
+    /// whose proper acceleration keeps its direction, with U = (u, γ):
+    /// x ↦ x + ((U0 + U1)·x) (U0 + U1) / (1 + γ_rel) - 2 (U0·x) U1, where γ_rel = -U0·U1.
+    ///
+    /// ```rust
+    /// # use rmath::{Matrix, Vector3, Vector4};
+    /// # use approx::assert_relative_eq;
+    /// let (u0, u1) = (Vector3::new(0.3, 0.0, 0.0), Vector3::new(0.1, 0.5, 0.0));
+    /// let m = Matrix::pure_boost(u0, u1);
+    /// assert_relative_eq!(m * Vector4::from_velocity(u0), Vector4::from_velocity(u1), epsilon = 1e-12);
+    /// // vectors normal to both are left alone
+    /// assert_relative_eq!(m * Vector4::new(0.0, 0.0, 1.0, 0.0), Vector4::new(0.0, 0.0, 1.0, 0.0));
+    /// ```
+    pub fn pure_boost(u0: Vector3, u1: Vector3) -> Matrix {
+        let (v0, v1) = (Vector4::from_velocity(u0), Vector4::from_velocity(u1));
+        let sum = v0 + v1;
+        let gamma = -v0.lorentz_dot(v1);
+        let column = |x: Vector4| {
+            x + sum * (sum.lorentz_dot(x) / (1.0 + gamma)) + v1 * (-2.0 * v0.lorentz_dot(x))
+        };
+        let c = [
+            column(Vector4::new(1.0, 0.0, 0.0, 0.0)),
+            column(Vector4::new(0.0, 1.0, 0.0, 0.0)),
+            column(Vector4::new(0.0, 0.0, 1.0, 0.0)),
+            column(Vector4::new(0.0, 0.0, 0.0, 1.0)),
+        ];
+        Matrix::new(
+            [c[0].x, c[1].x, c[2].x, c[3].x],
+            [c[0].y, c[1].y, c[2].y, c[3].y],
+            [c[0].z, c[1].z, c[2].z, c[3].z],
+            [c[0].ct, c[1].ct, c[2].ct, c[3].ct],
+        )
+    }
+
+    /// Thomas–Wigner rotation of a step from covariant velocity u0 to u1
+    ///
+    /// `Matrix::lorentz(u)` reaches each rest frame from the world frame by a pure boost.
+    /// Axes carried by `pure_boost` from the rest frame of u0 turn up in the rest frame of u1
+    /// rotated by this spatial rotation, which is the identity if u0 and u1 are parallel.
+    ///
+    /// ```rust
+    /// # use rmath::{Matrix, Vector3};
+    /// # use approx::assert_relative_eq;
+    /// let (u, x) = (Vector3::new(0.3, 0.1, 0.0), Vector3::new(0.2, -1.0, 0.7));
+    /// assert_relative_eq!(Matrix::wigner_rotation(u, u * 3.0) * x, x, epsilon = 1e-12);
+    /// ```
+    pub fn wigner_rotation(u0: Vector3, u1: Vector3) -> Matrix {
+        Matrix::lorentz(u1) * Matrix::pure_boost(u0, u1) * Matrix::lorentz(-u0)
+    }
+
     /// ```rust
     /// # use rmath::Matrix;
     /// let m = Matrix::new(
@@ -0,0 +1,286 @@
+use crate::{Matrix, Vector4};
+
+/// Residuals of Maxwell's equations for a field strength with upper indices at a point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaxwellResidual {
+    /// ∂_μ *F^{μν}, which vanishes if and only if ∂_[μ F_νρ] = 0
+    pub homogeneous: Vector4,
+    /// ∂_μ F^{μν}, which vanishes away from sources
+    pub inhomogeneous: Vector4,
+    /// Largest |∂_μ F^{ρσ}|, the size the residuals are compared with
+    pub scale: f64,
+}
+
+impl MaxwellResidual {
+    /// Largest component of the residuals over scale, 0 for a uniform field
+    pub fn relative(&self) -> f64 {
+        if self.scale == 0.0 {
+            return 0.0;
+        }
+        let max = |v: Vector4| v.x.abs().max(v.y.abs()).max(v.z.abs()).max(v.ct.abs());
+        max(self.homogeneous).max(max(self.inhomogeneous)) / self.scale
+    }
+}
+
+/// Check Maxwell's equations in vacuum at x by 4th order central differences of step h
+///
+/// field: field strength with upper indices at each event (x, y, z, ct), or why there is none
+pub fn maxwell_residual<E>(
+    field: impl Fn(Vector4) -> Result<Matrix, E>,
+    x: Vector4,
+    h: f64,
+) -> Result<MaxwellResidual, E> {
+    let basis = [
+        Vector4::new(1.0, 0.0, 0.0, 0.0),
+        Vector4::new(0.0, 1.0, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, 1.0, 0.0),
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ];
+    // d[μ] = ∂_μ F
+    let mut d = [Matrix::zero(); 4];
+    for (d, e) in d.iter_mut().zip(basis) {
+        *d = (field(x - e * (2.0 * h))?
+            + field(x - e * h)? * -8.0
+            + field(x + e * h)? * 8.0
+            + field(x + e * (2.0 * h))? * -1.0)
+            * (1.0 / (12.0 * h));
+    }
+    let divergence = |d: [Matrix; 4]| {
+        let mut v = [0.0; 4];
+        for (m, d) in d.iter().enumerate() {
+            for (v, f) in v.iter_mut().zip(d.rows[m]) {
+                *v += f;
+            }
+        }
+        Vector4::new(v[0], v[1], v[2], v[3])
+    };
+    let scale = d
+        .iter()
+        .flat_map(|d| d.rows.iter().flatten())
+        .fold(0.0_f64, |max, f| max.max(f.abs()));
+    Ok(MaxwellResidual {
+        // the dual is linear, so it commutes with ∂
+        homogeneous: divergence(d.map(|d| d.hodge_dual())),
+        inhomogeneous: divergence(d),
+        scale,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+    use rand_pcg::Mcg128Xsl64;
+
+    use super::*;
+    use crate::{
+        fermi_walker_moment, vec3, BoostedWorldLine, CircularWorldLine, DipoleKind,
+        HyperbolicWorldLine, InertialWorldLine, LineOscillateWorldLine, ParametricWorldLine,
+        ProperTime, StaticWorldLine, Vector3, WorldLine, WorldLineError,
+    };
+
+    /// Observers far enough from the origin to be away from the sources below
+    fn observers() -> Vec<Vector4> {
+        let mut rng = Mcg128Xsl64::new(1);
+        (0..20)
+            .map(|_| {
+                let dir = Vector3::new(
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                );
+                Vector4::from_ctv(
+                    rng.gen_range(0.0..20.0),
+                    dir.normalized() * rng.gen_range(5.0..15.0),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn point_charge_satisfies_maxwell() {
+        let c = 2.0;
+        let world_lines: Vec<(&str, Box<dyn WorldLine>)> = vec![
+            (
+                "static",
+                Box::new(StaticWorldLine::new(vec3(0.5, -0.3, 0.2))),
+            ),
+            (
+                "inertial",
+                Box::new(InertialWorldLine::new(
+                    Vector4::new(0.5, -0.3, 0.2, 10.0),
+                    vec3(0.3, -0.5, 0.1),
+                )),
+            ),
+            (
+                "line oscillate",
+                Box::new(
+                    LineOscillateWorldLine::new(vec3(0.5, -0.3, 0.2), vec3(1.0, 0.5, 0.0), 0.1, c)
+                        .unwrap(),
+                ),
+            ),
+            (
+                "hyperbolic",
+                Box::new(
+                    HyperbolicWorldLine::new(
+                        Vector4::new(0.0, 0.0, 0.0, 5.0),
+                        vec3(0.0, 0.1, 0.05),
+                    )
+                    .unwrap(),
+                ),
+            ),
+            (
+                "helical",
+                Box::new(
+                    CircularWorldLine::helical(
+                        vec3(0.5, -0.3, 0.2),
+                        vec3(1.0, 0.0, 0.0),
+                        vec3(0.1, 0.2, 1.0),
+                        0.1,
+                        0.2,
+                        c,
+                    )
+                    .unwrap(),
+                ),
+            ),
+            (
+                "boosted circular",
+                Box::new(BoostedWorldLine::new(
+                    CircularWorldLine::new(
+                        Vector3::zero(),
+                        vec3(1.0, 0.0, 0.0),
+                        vec3(0.0, 0.0, 1.0),
+                        0.1,
+                        c,
+                    )
+                    .unwrap(),
+                    vec3(0.2, 0.3, 0.0),
+                )),
+            ),
+            (
+                "parametric",
+                Box::new(
+                    ParametricWorldLine::new(
+                        |ct| vec3((0.1 * ct).sin(), 0.5 * (0.2 * ct).cos(), 0.0),
+                        0.2,
+                    )
+                    .unwrap()
+                    .velocity(|ct| vec3(0.1 * (0.1 * ct).cos(), -0.1 * (0.2 * ct).sin(), 0.0))
+                    .acceleration(|ct| {
+                        vec3(-0.01 * (0.1 * ct).sin(), -0.02 * (0.2 * ct).cos(), 0.0)
+                    }),
+                ),
+            ),
+        ];
+        for (name, wl) in world_lines.iter() {
+            let field = |x: Vector4| {
+                let (y, u, a) = wl.past_intersection(c, x)?;
+                Ok::<_, WorldLineError>(Matrix::field_strength(2.0, (y - x).spatial(), u, a))
+            };
+            let mut checked = 0;
+            for x in observers() {
+                match maxwell_residual(field, x, 1e-2) {
+                    Ok(residual) => {
+                        assert!(residual.relative() < 1e-6, "{name} {x:?} {residual:?}");
+                        checked += 1;
+                    }
+                    // beyond the horizon of the hyperbolic motion
+                    Err(e) => assert!(e.is_not_arrived(), "{name} {x:?} {e}"),
+                }
+            }
+            assert!(checked >= 10, "{name} {checked}");
+        }
+    }
+
+    #[test]
+    fn point_dipole_satisfies_maxwell() {
+        let c = 2.0;
+        let world_lines: Vec<(&str, Box<dyn ProperTime>)> = vec![
+            (
+                "inertial",
+                Box::new(InertialWorldLine::new(
+                    Vector4::new(0.5, -0.3, 0.2, 10.0),
+                    vec3(0.3, -0.5, 0.1),
+                )),
+            ),
+            (
+                "hyperbolic",
+                Box::new(
+                    HyperbolicWorldLine::new(
+                        Vector4::new(0.0, 0.0, 0.0, 5.0),
+                        vec3(0.0, 0.1, 0.05),
+                    )
+                    .unwrap(),
+                ),
+            ),
+            (
+                "circular",
+                Box::new(
+                    CircularWorldLine::new(
+                        vec3(0.5, -0.3, 0.2),
+                        vec3(1.0, 0.0, 0.0),
+                        vec3(0.1, 0.2, 1.0),
+                        0.1,
+                        c,
+                    )
+                    .unwrap(),
+                ),
+            ),
+        ];
+        let (p, k) = (vec3(1.0, -2.0, 0.5), 0.4);
+        for (name, wl) in world_lines.iter() {
+            for (kind, advanced) in [
+                (DipoleKind::Electric, false),
+                (DipoleKind::Magnetic, false),
+                (DipoleKind::Electric, true),
+                (DipoleKind::Magnetic, true),
+            ] {
+                let field = |x: Vector4| {
+                    let (y, u, a) = if advanced {
+                        wl.future_intersection(c, x)?
+                    } else {
+                        wl.past_intersection(c, x)?
+                    };
+                    let jerk = wl.jerk(c, y, 1e-2)?;
+                    let (sin, cos) = (k * wl.proper_time(c, y.ct)?).sin_cos();
+                    let moment = [p * cos, p * (-k * sin), p * (-k * k * cos)];
+                    let moment = fermi_walker_moment(moment, u, a, jerk);
+                    let l = (y - x).spatial();
+                    let (near, radiation) = if advanced {
+                        Matrix::advanced_dipole_field_strength_parts(kind, moment, l, u, a, jerk)
+                    } else {
+                        Matrix::dipole_field_strength_parts(kind, moment, l, u, a, jerk)
+                    };
+                    Ok::<_, WorldLineError>(near + radiation)
+                };
+                let mut checked = 0;
+                for x in observers() {
+                    match maxwell_residual(field, x, 1e-2) {
+                        Ok(residual) => {
+                            assert!(
+                                residual.relative() < 1e-6,
+                                "{name} {kind:?} {advanced} {x:?} {residual:?}"
+                            );
+                            checked += 1;
+                        }
+                        // beyond the horizons of the hyperbolic motion
+                        Err(e) => assert!(e.is_not_arrived(), "{name} {x:?} {e}"),
+                    }
+                }
+                assert!(checked >= 10, "{name} {advanced} {checked}");
+            }
+        }
+    }
+
+    #[test]
+    fn broken_field_violates_maxwell() {
+        // a Coulomb field falling off as 1/r³ has sources everywhere
+        let field = |x: Vector4| {
+            let l = -x.spatial();
+            let fs = Matrix::field_strength(2.0, l, Vector3::zero(), Vector3::zero());
+            Ok::<_, ()>(fs * (1.0 / l.magnitude()))
+        };
+        let residual = maxwell_residual(field, Vector4::new(3.0, 4.0, 5.0, 0.0), 1e-2).unwrap();
+        assert!(residual.homogeneous.spatial().magnitude() + residual.homogeneous.ct.abs() < 1e-10);
+        assert!(residual.relative() > 1e-2, "{residual:?}");
+    }
+}
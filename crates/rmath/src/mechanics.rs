@@ -1,4 +1,6 @@
-use crate::{Matrix, Vector3, Vector4};
+use std::collections::VecDeque;
+
+use crate::{Matrix, Quaternion, Vector3, Vector4};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct PhaseSpace {
@@ -27,11 +29,310 @@ impl PhaseSpace {
         self.position += Vector4::from_velocity(self.velocity) * ds;
     }
 
+    /// Step in the field strength fs for a charge of charge-to-mass ratio q_over_m,
+    /// with `Matrix::lorentz_four_force` in the world frame
+    pub fn tick_in_field(&mut self, ds: f64, fs: Matrix, q_over_m: f64) {
+        let force = fs.lorentz_four_force(q_over_m, 1.0, Vector4::from_velocity(self.velocity));
+        self.tick_in_world_frame(ds, force.spatial());
+    }
+
+    /// Step ds by integrator, with du/ds = force(x, u) in the world frame
+    ///
+    /// `Integrator::Boris` needs the field itself, so here it steps as `Integrator::Leapfrog`.
+    pub fn step(
+        &mut self,
+        integrator: Integrator,
+        ds: f64,
+        force: impl Fn(Vector4, Vector3) -> Vector3,
+    ) {
+        let (x, u) = (self.position, self.velocity);
+        match integrator {
+            Integrator::Euler => self.tick_in_world_frame(ds, force(x, u)),
+            Integrator::RungeKutta4 => {
+                let k1 = (Vector4::from_velocity(u), force(x, u));
+                let stage = |k: (Vector4, Vector3), h: f64| {
+                    let u = u + k.1 * h;
+                    (Vector4::from_velocity(u), force(x + k.0 * h, u))
+                };
+                let k2 = stage(k1, ds / 2.0);
+                let k3 = stage(k2, ds / 2.0);
+                let k4 = stage(k3, ds);
+                self.position = x + (k1.0 + (k2.0 + k3.0) * 2.0 + k4.0) * (ds / 6.0);
+                self.velocity = u + (k1.1 + (k2.1 + k3.1) * 2.0 + k4.1) * (ds / 6.0);
+            }
+            Integrator::Boris | Integrator::Leapfrog => {
+                let half = x + Vector4::from_velocity(u) * (ds / 2.0);
+                // the kick takes u at the middle too, for forces depending on it
+                let middle = u + force(half, u) * (ds / 2.0);
+                self.velocity += force(half, middle) * ds;
+                self.position = half + Vector4::from_velocity(self.velocity) * (ds / 2.0);
+            }
+        }
+    }
+
+    /// Step ds by integrator for a charge of charge-to-mass ratio q_over_m
+    /// in the field strength field(x), with `Matrix::lorentz_four_force`
+    ///
+    /// extra: another du/ds in the world frame held over the step, such as radiation reaction
+    pub fn step_in_field(
+        &mut self,
+        integrator: Integrator,
+        ds: f64,
+        field: impl Fn(Vector4) -> Matrix,
+        q_over_m: f64,
+        extra: Vector3,
+    ) {
+        if integrator != Integrator::Boris {
+            let force = |x: Vector4, u: Vector3| {
+                let u = Vector4::from_velocity(u);
+                field(x).lorentz_four_force(q_over_m, 1.0, u).spatial() + extra
+            };
+            self.step(integrator, ds, force);
+            return;
+        }
+        // in x_0 the Lorentz force is du/dx_0 = q/m (E/c + u/γ × B),
+        // which Boris splits into two electric half kicks around a magnetic rotation
+        let (x, u) = (self.position, self.velocity);
+        let dx0 = u.gamma() * ds;
+        let half = x + Vector4::from_velocity(u) * (ds / 2.0);
+        let fs = field(half);
+        let (e, b) = (
+            fs.field_strength_to_electric_field(1.0),
+            fs.field_strength_to_magnetic_field(),
+        );
+        let h = q_over_m * dx0 / 2.0;
+        let kick = e * h + extra * (ds / 2.0);
+        let minus = u + kick;
+        let t = b * (h / minus.gamma());
+        let s = t * (2.0 / (1.0 + t.magnitude2()));
+        let plus = minus + (minus + minus.cross(t)).cross(s);
+        self.velocity = plus + kick;
+        let drift = self.velocity / self.velocity.gamma();
+        self.position = half + Vector4::from_ctv(1.0, drift) * (dx0 / 2.0);
+    }
+
     pub fn change_c(&mut self, current_c: f64, new_c: f64) {
         self.velocity *= current_c / new_c;
     }
 }
 
+/// Scheme advancing a `PhaseSpace` by one step of proper time
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Integrator {
+    /// First order, u is kicked and then x drifts with the new u as in `tick_in_world_frame`
+    #[default]
+    Euler,
+    /// Classic fourth order Runge–Kutta in proper time
+    RungeKutta4,
+    /// Relativistic Boris pusher, second order, whose magnetic rotation keeps |u| exactly
+    Boris,
+    /// Drift-kick-drift, second order and symplectic for forces depending on x only,
+    /// as the kick then reduces to a single force evaluation
+    Leapfrog,
+}
+
+impl Integrator {
+    /// Order of the global error in the step size
+    pub fn order(self) -> u32 {
+        match self {
+            Integrator::Euler => 1,
+            Integrator::RungeKutta4 => 4,
+            Integrator::Boris | Integrator::Leapfrog => 2,
+        }
+    }
+}
+
+impl std::str::FromStr for Integrator {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "euler" => Ok(Integrator::Euler),
+            "rk4" => Ok(Integrator::RungeKutta4),
+            "boris" => Ok(Integrator::Boris),
+            "leapfrog" => Ok(Integrator::Leapfrog),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Step-size control for a `PhaseSpace` from a local error estimate
+///
+/// Steps ds are in proper time s = cτ, as in `PhaseSpace::step_in_field`.
+///
+/// Each step is taken once with ds and again as two halves; their difference estimates
+/// the error of the halves, which are kept if it is within tolerance. The next ds is scaled
+/// by the integrator's order so that the estimate would just meet the tolerance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdaptiveStepper {
+    /// Allowed error of u per step, relative to 1 + |u|
+    tolerance: f64,
+    min_step: f64,
+    max_step: f64,
+    /// ds to try next
+    step: f64,
+    accepted: usize,
+    rejected: usize,
+    /// (x_0 at the start, proper-time step ds) of the latest accepted steps, oldest first
+    history: VecDeque<(f64, f64)>,
+    capacity: usize,
+}
+
+impl AdaptiveStepper {
+    /// Start with ds = step, between step / 64 and step * 8 with the history of 1024 steps
+    pub fn new(step: f64, tolerance: f64) -> AdaptiveStepper {
+        AdaptiveStepper {
+            tolerance,
+            min_step: step / 64.0,
+            max_step: step * 8.0,
+            step,
+            accepted: 0,
+            rejected: 0,
+            history: VecDeque::new(),
+            capacity: 1024,
+        }
+    }
+
+    /// Smallest ds, taken even if the error is above tolerance
+    pub fn min_step(mut self, min_step: f64) -> AdaptiveStepper {
+        self.min_step = min_step;
+        self.step = self.step.max(min_step);
+        self
+    }
+
+    pub fn max_step(mut self, max_step: f64) -> AdaptiveStepper {
+        self.max_step = max_step;
+        self.step = self.step.min(max_step);
+        self
+    }
+
+    /// Number of the latest steps kept in `history`
+    pub fn capacity(mut self, capacity: usize) -> AdaptiveStepper {
+        self.capacity = capacity;
+        self.history.truncate(capacity);
+        self
+    }
+
+    /// ds the next step will try
+    pub fn step_size(&self) -> f64 {
+        self.step
+    }
+
+    pub fn accepted(&self) -> usize {
+        self.accepted
+    }
+
+    pub fn rejected(&self) -> usize {
+        self.rejected
+    }
+
+    /// (x_0 at the start, proper-time step ds) of the latest accepted steps, oldest first
+    pub fn history(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.history.iter().copied()
+    }
+
+    /// Change the length unit of ds as `PhaseSpace::change_c` does for u
+    pub fn change_c(&mut self, current_c: f64, new_c: f64) {
+        let r = new_c / current_c;
+        self.step *= r;
+        self.min_step *= r;
+        self.max_step *= r;
+    }
+
+    /// Advance phase_space by one accepted step, with advance(p, ds) stepping p by ds
+    /// with an integrator of order, and return ds taken
+    pub fn step(
+        &mut self,
+        order: u32,
+        phase_space: &mut PhaseSpace,
+        mut advance: impl FnMut(&mut PhaseSpace, f64),
+    ) -> f64 {
+        loop {
+            let ds = self.step;
+            let mut whole = *phase_space;
+            advance(&mut whole, ds);
+            let mut halves = *phase_space;
+            advance(&mut halves, ds / 2.0);
+            advance(&mut halves, ds / 2.0);
+            // the halves are closer by 2^order, so their error is the difference over 2^order - 1
+            let difference = (halves.velocity - whole.velocity).magnitude();
+            let error = difference
+                / (2.0_f64.powi(order as i32) - 1.0)
+                / (1.0 + halves.velocity.magnitude());
+            let factor = if error > 0.0 {
+                0.9 * (self.tolerance / error).powf(1.0 / (order + 1) as f64)
+            } else {
+                5.0
+            };
+            self.step = (ds * factor.clamp(0.2, 5.0)).clamp(self.min_step, self.max_step);
+            if error <= self.tolerance || ds <= self.min_step {
+                self.accepted += 1;
+                if self.history.len() >= self.capacity {
+                    self.history.pop_front();
+                }
+                if self.capacity > 0 {
+                    self.history.push_back((phase_space.position.ct, ds));
+                }
+                *phase_space = halves;
+                return ds;
+            }
+            self.rejected += 1;
+        }
+    }
+}
+
+/// Spatial axes carried along a world line by Fermi–Walker transport, like gyroscopes
+///
+/// They are kept as their rotation from the axes of `Matrix::lorentz(u)`, the rest frame
+/// reached from the world frame by a pure boost. When u turns, successive boosts do not
+/// commute and the transported axes pick up the Thomas–Wigner rotation against them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FermiWalkerFrame {
+    rotation: Quaternion,
+}
+
+impl Default for FermiWalkerFrame {
+    fn default() -> Self {
+        FermiWalkerFrame::new()
+    }
+}
+
+impl FermiWalkerFrame {
+    /// Axes of the pure-boost rest frame
+    pub const fn new() -> FermiWalkerFrame {
+        FermiWalkerFrame {
+            rotation: Quaternion::one(),
+        }
+    }
+
+    /// Carry the axes along a step from u0 to u1 and return the rotation of the step,
+    /// which is exact for a proper acceleration keeping its direction over the step
+    pub fn transport(&mut self, u0: Vector3, u1: Vector3) -> Quaternion {
+        let step = Quaternion::from_rotation_matrix(Matrix::wigner_rotation(u0, u1));
+        self.rotation = (step * self.rotation).normalized();
+        step
+    }
+
+    /// Accumulated Thomas–Wigner rotation, taking the axes of `Matrix::lorentz(u)`
+    /// to the transported axes, both in the rest frame
+    pub fn rotation(&self) -> Quaternion {
+        self.rotation
+    }
+}
+
+/// Self-force of a radiating charge to add to the Lorentz force
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RadiationReaction {
+    /// Charges do not lose the energy they radiate
+    #[default]
+    None,
+    /// `Matrix::landau_lifshitz_force`, which needs the derivative of the field along the world line
+    LandauLifshitz,
+    /// `abraham_lorentz_dirac_force` with the jerk taken as the change of the Lorentz force
+    /// since the previous step
+    ReducedAbrahamLorentzDirac,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -59,4 +360,264 @@ mod test {
         );
         assert!(p1.position.ct > p2.position.ct);
     }
+
+    #[test]
+    fn gyration() {
+        // uniform B along z, B = F^{xy}
+        let b = 0.5;
+        let fs = Matrix::new(
+            [0.0, b, 0.0, 0.0],
+            [-b, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+        );
+        let (u, q_over_m) = (2.0, 0.25);
+        let mut p = PhaseSpace::new(Vector3::new(u, 0.0, 0.0), Vector4::zero());
+        // in proper time, the charge turns at q/m B and the radius is u / (q/m B)
+        let omega = q_over_m * b;
+        let n = 100000;
+        let ds = std::f64::consts::TAU / omega / n as f64;
+        for _ in 0..n {
+            p.tick_in_field(ds, fs, q_over_m);
+        }
+        assert_relative_eq!(p.velocity, Vector3::new(u, 0.0, 0.0), epsilon = 1e-3);
+        assert_relative_eq!(
+            p.position.spatial(),
+            Vector3::zero(),
+            epsilon = 1e-2 * u / omega
+        );
+        assert_relative_eq!(
+            p.position.ct,
+            (1.0 + u * u).sqrt() * std::f64::consts::TAU / omega,
+            max_relative = 1e-3
+        );
+    }
+
+    const INTEGRATORS: [Integrator; 4] = [
+        Integrator::Euler,
+        Integrator::RungeKutta4,
+        Integrator::Boris,
+        Integrator::Leapfrog,
+    ];
+
+    /// Error of each integrator after one gyration in a uniform B with n steps
+    fn gyration_errors(n: usize) -> [f64; 4] {
+        let b = Vector3::new(0.0, 0.0, 0.5);
+        let fs = Matrix::field_strength_from_fields(Vector3::zero(), b, 1.0);
+        let (u, q_over_m) = (2.0, 0.25);
+        let ds = std::f64::consts::TAU / (q_over_m * b.z) / n as f64;
+        INTEGRATORS.map(|integrator| {
+            let mut p = PhaseSpace::new(Vector3::new(u, 0.0, 0.0), Vector4::zero());
+            for _ in 0..n {
+                p.step_in_field(integrator, ds, |_| fs, q_over_m, Vector3::zero());
+            }
+            if integrator == Integrator::Boris {
+                // the rotation keeps the speed at any step
+                assert_relative_eq!(p.velocity.magnitude(), u, max_relative = 1e-12);
+            }
+            (p.velocity - Vector3::new(u, 0.0, 0.0)).magnitude()
+                + p.position.spatial().magnitude() * q_over_m * b.z
+        })
+    }
+
+    #[test]
+    fn integrators_on_gyration() {
+        let coarse = gyration_errors(200);
+        let fine = gyration_errors(400);
+        let [euler, rk4, boris, leapfrog] = fine;
+        assert!(rk4 < 1e-6, "{fine:?}");
+        assert!(boris < 2e-3 && leapfrog < 2e-3, "{fine:?}");
+        assert!(euler > 10.0 * boris, "{fine:?}");
+        // convergence orders 1, 4, 2 and 2
+        for (order, (coarse, fine)) in [1, 4, 2, 2].into_iter().zip(coarse.into_iter().zip(fine)) {
+            let observed = (coarse / fine).log2();
+            assert!((observed - order as f64).abs() < 0.3, "{order} {observed}");
+        }
+    }
+
+    #[test]
+    fn integrators_on_hyperbolic_motion() {
+        // uniform E along x: u = sinh κs, x = (cosh κs - 1) / κ and x_0 = sinh κs / κ
+        let e = Vector3::new(0.5, 0.0, 0.0);
+        let fs = Matrix::field_strength_from_fields(e, Vector3::zero(), 1.0);
+        let q_over_m = 2.0;
+        let kappa = q_over_m * e.x;
+        let (s, n) = (3.0, 300);
+        let ds = s / n as f64;
+        let exact = Vector4::new(
+            ((kappa * s).cosh() - 1.0) / kappa,
+            0.0,
+            0.0,
+            (kappa * s).sinh() / kappa,
+        );
+        for (integrator, tolerance) in INTEGRATORS.into_iter().zip([1e-1, 1e-7, 1e-3, 1e-3]) {
+            let mut p = PhaseSpace::new(Vector3::zero(), Vector4::zero());
+            for _ in 0..n {
+                p.step_in_field(integrator, ds, |_| fs, q_over_m, Vector3::zero());
+            }
+            if integrator == Integrator::Boris {
+                // it steps in x_0 rather than in s, so compare where it got to
+                let u = (kappa * p.position.ct).asinh();
+                assert_relative_eq!(p.velocity.x, u.sinh(), max_relative = tolerance);
+                assert_relative_eq!(
+                    p.position.x,
+                    (u.cosh() - 1.0) / kappa,
+                    max_relative = tolerance
+                );
+            } else {
+                assert_relative_eq!(p.velocity.x, (kappa * s).sinh(), max_relative = tolerance);
+                assert_relative_eq!(p.position, exact, max_relative = tolerance);
+            }
+        }
+    }
+
+    #[test]
+    fn leapfrog_keeps_kepler_energy() {
+        // slow eccentric orbit in a Newtonian 1/r² pull, whose energy should stay put
+        let k = 1.0;
+        let force = |x: Vector4, _: Vector3| {
+            let r = x.spatial();
+            r * (-k / r.magnitude().powi(3))
+        };
+        let energy =
+            |p: &PhaseSpace| p.velocity.magnitude2() / 2.0 - k / p.position.spatial().magnitude();
+        let (r, orbits, n) = (100.0, 5, 2000);
+        let v = 0.6 * (k / r).sqrt();
+        let ds = std::f64::consts::TAU * r / v / n as f64;
+        let errors = INTEGRATORS.map(|integrator| {
+            let mut p = PhaseSpace::new(Vector3::new(0.0, v, 0.0), Vector4::new(r, 0.0, 0.0, 0.0));
+            let e0 = energy(&p);
+            let mut error = 0.0_f64;
+            for _ in 0..orbits * n {
+                p.step(integrator, ds, force);
+                error = error.max(((energy(&p) - e0) / e0).abs());
+            }
+            error
+        });
+        let [euler, rk4, _, leapfrog] = errors;
+        assert!(leapfrog < 1e-3 && rk4 < 1e-3, "{errors:?}");
+        assert!(euler > 10.0 * leapfrog, "{errors:?}");
+        assert_eq!("rk4".parse(), Ok(Integrator::RungeKutta4));
+    }
+
+    /// Accepted steps and their largest and smallest ds for a gyration with tolerance
+    fn adaptive_gyration(
+        integrator: Integrator,
+        tolerance: f64,
+        n: f64,
+    ) -> (PhaseSpace, AdaptiveStepper) {
+        let fs =
+            Matrix::field_strength_from_fields(Vector3::zero(), Vector3::new(0.0, 0.0, 0.5), 1.0);
+        let (u, q_over_m) = (2.0, 0.25);
+        let period = std::f64::consts::TAU / (q_over_m * 0.5);
+        let mut stepper = AdaptiveStepper::new(period / n, tolerance)
+            .min_step(period / n / 1e3)
+            .max_step(period);
+        let mut p = PhaseSpace::new(Vector3::new(u, 0.0, 0.0), Vector4::zero());
+        let mut s = 0.0;
+        while s < period {
+            s += stepper.step(integrator.order(), &mut p, |p, ds| {
+                p.step_in_field(integrator, ds, |_| fs, q_over_m, Vector3::zero())
+            });
+        }
+        // back to where one period ends
+        p.step_in_field(integrator, period - s, |_| fs, q_over_m, Vector3::zero());
+        (p, stepper)
+    }
+
+    #[test]
+    fn adaptive_step_meets_tolerance() {
+        for integrator in INTEGRATORS {
+            let (loose, loose_stepper) = adaptive_gyration(integrator, 1e-4, 4.0);
+            let (tight, tight_stepper) = adaptive_gyration(integrator, 1e-7, 4.0);
+            let error = |p: PhaseSpace| (p.velocity - Vector3::new(2.0, 0.0, 0.0)).magnitude();
+            assert!(error(tight) < error(loose), "{integrator:?}");
+            assert!(
+                tight_stepper.accepted() > loose_stepper.accepted(),
+                "{integrator:?}"
+            );
+            // starting too coarse, the first tries are rejected
+            assert!(loose_stepper.rejected() > 0, "{integrator:?}");
+            assert_eq!(
+                tight_stepper.history().count(),
+                tight_stepper.accepted().min(1024)
+            );
+        }
+        // the global error follows the tolerance per step
+        let (p, _) = adaptive_gyration(Integrator::RungeKutta4, 1e-9, 4.0);
+        assert_relative_eq!(p.velocity, Vector3::new(2.0, 0.0, 0.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn adaptive_step_follows_the_force() {
+        // a fast flyby of a 1/r² pull takes small steps near closest approach
+        let k = 1.0;
+        let force = |x: Vector4, _: Vector3| {
+            let r = x.spatial();
+            r * (-k / r.magnitude().powi(3))
+        };
+        let energy =
+            |p: &PhaseSpace| p.velocity.magnitude2() / 2.0 - k / p.position.spatial().magnitude();
+        let mut stepper = AdaptiveStepper::new(1.0, 1e-6).min_step(1e-4).max_step(4.0);
+        let mut p = PhaseSpace::new(
+            Vector3::new(0.0, 2.0, 0.0),
+            Vector4::new(0.5, -20.0, 0.0, 0.0),
+        );
+        let e0 = energy(&p);
+        let mut s = 0.0;
+        while s < 20.0 {
+            s += stepper.step(2, &mut p, |p, ds| p.step(Integrator::Leapfrog, ds, force));
+        }
+        let steps = stepper.history().map(|(_, ds)| ds).collect::<Vec<_>>();
+        let (first, last) = (steps[0], steps[steps.len() - 1]);
+        let smallest = steps.iter().copied().fold(f64::INFINITY, f64::min);
+        assert!(
+            first > 10.0 * smallest && last > 10.0 * smallest,
+            "{steps:?}"
+        );
+        // energy survives the encounter
+        assert_relative_eq!(energy(&p), e0, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn thomas_precession() {
+        // after one orbit at γ the transported axes are turned by 2π (γ - 1) against the orbit
+        let gamma: f64 = 1.2;
+        let u = (gamma * gamma - 1.0).sqrt();
+        let n = 20000;
+        let velocity = |i: usize| {
+            let (sin, cos) = (std::f64::consts::TAU * i as f64 / n as f64).sin_cos();
+            Vector3::new(-sin, cos, 0.0) * u
+        };
+        let mut frame = FermiWalkerFrame::new();
+        for i in 0..n {
+            frame.transport(velocity(i), velocity(i + 1));
+        }
+        let (angle, axis) = frame.rotation().to_axis_angle();
+        let expected = std::f64::consts::TAU * (gamma - 1.0);
+        assert_relative_eq!(angle.0, expected, max_relative = 1e-6);
+        assert_relative_eq!(axis, Vector3::new(0.0, 0.0, -1.0), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn wigner_rotation_of_two_boosts() {
+        // boosting along x then along y in the new frame is not a boost along any one direction
+        let (a, b) = (Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        let u1 = Matrix::lorentz(-a) * Vector4::from_velocity(b);
+        let mut frame = FermiWalkerFrame::new();
+        frame.transport(Vector3::zero(), a);
+        frame.transport(a, u1.spatial());
+        // the composition is boost(u1) R, with tan θ = sinh α sinh β / (cosh α + cosh β)
+        let (ga, gb) = (a.gamma(), b.gamma());
+        let expected = (a.x * b.y / (ga + gb)).atan();
+        let (angle, axis) = frame.rotation().to_axis_angle();
+        assert_relative_eq!(angle.0, expected, max_relative = 1e-12);
+        assert_relative_eq!(axis.magnitude(), axis.z.abs(), max_relative = 1e-12);
+        // collinear boosts do not rotate
+        let mut frame = FermiWalkerFrame::new();
+        frame.transport(Vector3::zero(), a);
+        frame.transport(a, a * 3.0);
+        frame.transport(a * 3.0, -a);
+        assert_relative_eq!(frame.rotation().to_axis_angle().0 .0, 0.0, epsilon = 1e-12);
+    }
 }
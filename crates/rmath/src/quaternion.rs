@@ -129,6 +129,72 @@ impl Quaternion {
         }
     }
 
+    /// Get the rotation of the upper left 3x3 block of a rotation matrix,
+    /// the inverse of `Matrix::from`
+    ///
+    /// ```rust
+    /// # use rmath::{Quaternion, Vector3, Matrix, Deg};
+    /// # use approx::assert_relative_eq;
+    /// let q = Quaternion::from_axis(Deg(170.0), Vector3::new(1.0, -2.0, 0.5));
+    /// let r = Quaternion::from_rotation_matrix(Matrix::from(q));
+    /// let x = Vector3::new(0.2, -1.0, 0.7);
+    /// assert_relative_eq!(r * x, q * x, epsilon = 1e-12);
+    /// ```
+    pub fn from_rotation_matrix(m: Matrix) -> Quaternion {
+        let r = m.rows;
+        let trace = r[0][0] + r[1][1] + r[2][2];
+        // take the square root of the largest component for accuracy
+        let q = if trace > 0.0 {
+            let s = 2.0 * (1.0 + trace).sqrt();
+            Quaternion::new(
+                s / 4.0,
+                (r[2][1] - r[1][2]) / s,
+                (r[0][2] - r[2][0]) / s,
+                (r[1][0] - r[0][1]) / s,
+            )
+        } else if r[0][0] > r[1][1] && r[0][0] > r[2][2] {
+            let s = 2.0 * (1.0 + r[0][0] - r[1][1] - r[2][2]).sqrt();
+            Quaternion::new(
+                (r[2][1] - r[1][2]) / s,
+                s / 4.0,
+                (r[0][1] + r[1][0]) / s,
+                (r[0][2] + r[2][0]) / s,
+            )
+        } else if r[1][1] > r[2][2] {
+            let s = 2.0 * (1.0 + r[1][1] - r[0][0] - r[2][2]).sqrt();
+            Quaternion::new(
+                (r[0][2] - r[2][0]) / s,
+                (r[0][1] + r[1][0]) / s,
+                s / 4.0,
+                (r[1][2] + r[2][1]) / s,
+            )
+        } else {
+            let s = 2.0 * (1.0 + r[2][2] - r[0][0] - r[1][1]).sqrt();
+            Quaternion::new(
+                (r[1][0] - r[0][1]) / s,
+                (r[0][2] + r[2][0]) / s,
+                (r[1][2] + r[2][1]) / s,
+                s / 4.0,
+            )
+        };
+        q.normalized()
+    }
+
+    /// Rotation angle in [0, π] and its axis, or the x axis for no rotation
+    pub fn to_axis_angle(&self) -> (Rad, Vector3) {
+        let q = if self.s < 0.0 {
+            Quaternion::new(-self.s, -self.x, -self.y, -self.z)
+        } else {
+            *self
+        };
+        let v = Vector3::new(q.x, q.y, q.z);
+        let sin = v.magnitude();
+        if sin <= f64::EPSILON {
+            return (Rad(0.0), Vector3::X_AXIS);
+        }
+        (Rad(2.0 * sin.atan2(q.s)), v / sin)
+    }
+
     pub fn is_nan(&self) -> bool {
         self.s.is_nan() || self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
     }
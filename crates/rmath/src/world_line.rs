@@ -1,8 +1,115 @@
-use crate::{Vector3, Vector4};
+use crate::{Matrix, Vector3, Vector4};
+
+/// Why a world line could not answer a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorldLineError {
+    /// Too few samples, or the samples end before the observer's light cone
+    HistoryTooShort,
+    /// The observer's light cone misses the whole (recorded) world line,
+    /// e.g. the field has not arrived yet
+    SpaceLike,
+    /// The root finder stopped before reaching the tolerance
+    NotConverged,
+    /// The world line would move at or faster than light
+    FasterThanLight,
+    /// A parameter is out of its domain
+    InvalidParameter,
+    /// The world line does not implement this query
+    Unsupported,
+}
+
+impl WorldLineError {
+    /// The light cone has not reached the (recorded) world line yet,
+    /// so the query may succeed later, as opposed to a failure
+    pub fn is_not_arrived(self) -> bool {
+        matches!(
+            self,
+            WorldLineError::HistoryTooShort | WorldLineError::SpaceLike
+        )
+    }
+}
+
+impl std::fmt::Display for WorldLineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            WorldLineError::HistoryTooShort => "history of the world line is too short",
+            WorldLineError::SpaceLike => "observer is space-like to the whole world line",
+            WorldLineError::NotConverged => "light cone intersection did not converge",
+            WorldLineError::FasterThanLight => "world line is faster than light",
+            WorldLineError::InvalidParameter => "invalid world line parameter",
+            WorldLineError::Unsupported => "unsupported by the world line",
+        };
+        f.write_str(s)
+    }
+}
+
+impl std::error::Error for WorldLineError {}
 
 pub trait WorldLine {
     /// x is observer's position
-    fn past_intersection(&self, c: f64, x: Vector4) -> Option<(Vector4, Vector3, Vector3)>;
+    fn past_intersection(
+        &self,
+        c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError>;
+
+    /// past_intersection of many observers at once
+    ///
+    /// World lines solved iteratively start each solve from the previous observer's
+    /// solution, so ordering xs so that consecutive observers are close is much faster.
+    fn past_intersections(
+        &self,
+        c: f64,
+        xs: &[Vector4],
+    ) -> Vec<Result<(Vector4, Vector3, Vector3), WorldLineError>> {
+        xs.iter().map(|&x| self.past_intersection(c, x)).collect()
+    }
+
+    /// Intersection with observer's future light cone, for advanced fields
+    ///
+    /// x is observer's position
+    fn future_intersection(
+        &self,
+        _c: f64,
+        _x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        Err(WorldLineError::Unsupported)
+    }
+}
+
+/// Proper time along a world line, in the same length unit as x_0 (that is cτ)
+pub trait ProperTime: WorldLine {
+    /// Clock reading of the charge at x_0 = ct
+    ///
+    /// Each world line documents where its clock reads zero.
+    fn proper_time(&self, c: f64, ct: f64) -> Result<f64, WorldLineError>;
+
+    /// Proper time elapsed from x_0 = ct0 to x_0 = ct1, negative if ct1 < ct0
+    fn proper_time_between(&self, c: f64, ct0: f64, ct1: f64) -> Result<f64, WorldLineError> {
+        Ok(self.proper_time(c, ct1)? - self.proper_time(c, ct0)?)
+    }
+
+    /// Clock reading seen by observer at x
+    fn past_proper_time(&self, c: f64, x: Vector4) -> Result<f64, WorldLineError> {
+        let (y, _, _) = self.past_intersection(c, x)?;
+        self.proper_time(c, y.ct)
+    }
+
+    /// Derivative of covariant acceleration by cτ at the event y on the world line
+    ///
+    /// Differences the accelerations seen from y ± h u, about cτ = ±h away from y,
+    /// so h should be small against the proper time the acceleration takes to change.
+    fn jerk(&self, c: f64, y: Vector4, h: f64) -> Result<Vector3, WorldLineError> {
+        let s = self.proper_time(c, y.ct)?;
+        let (_, u, a) = self.past_intersection(c, y)?;
+        let seen = |h: f64| {
+            let (y, _, a) = self.past_intersection(c, y + Vector4::from_velocity(u) * h)?;
+            Ok::<_, WorldLineError>((self.proper_time(c, y.ct)? - s, a))
+        };
+        let ((s0, a0), (s1, a1)) = (seen(-h)?, seen(h)?);
+        // slope at s of the parabola through the three points, s0 < 0 < s1
+        Ok((a1 - a) * (-s0 / s1 / (s1 - s0)) + (a - a0) * (s1 / -s0 / (s1 - s0)))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -18,9 +125,26 @@ impl StaticWorldLine {
 }
 
 impl WorldLine for StaticWorldLine {
-    fn past_intersection(&self, _c: f64, x: Vector4) -> Option<(Vector4, Vector3, Vector3)> {
+    fn past_intersection(
+        &self,
+        _c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
         let t = x.ct - (x.spatial() - self.pos).magnitude();
-        Some((
+        Ok((
+            Vector4::from_ctv(t, self.pos),
+            Vector3::zero(),
+            Vector3::zero(),
+        ))
+    }
+
+    fn future_intersection(
+        &self,
+        _c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let t = x.ct + (x.spatial() - self.pos).magnitude();
+        Ok((
             Vector4::from_ctv(t, self.pos),
             Vector3::zero(),
             Vector3::zero(),
@@ -28,6 +152,87 @@ impl WorldLine for StaticWorldLine {
     }
 }
 
+/// Zero at x_0 = 0
+impl ProperTime for StaticWorldLine {
+    fn proper_time(&self, _c: f64, ct: f64) -> Result<f64, WorldLineError> {
+        Ok(ct)
+    }
+}
+
+/// Uniform motion
+///
+/// p(x_0) = origin + v (x_0 - origin.ct), v = u / γ
+#[derive(Debug, Clone)]
+pub struct InertialWorldLine {
+    origin: Vector4,
+    velocity: Vector3,
+}
+
+impl InertialWorldLine {
+    /// origin: any event on the world line
+    /// u: covariant velocity
+    pub fn new(origin: Vector4, u: Vector3) -> InertialWorldLine {
+        InertialWorldLine {
+            origin,
+            velocity: u,
+        }
+    }
+
+    /// Both x_0 - origin.ct of the intersections with observer's light cone, past first
+    ///
+    /// (T - τ)² = |d - v τ|² is (1 - v²) τ² - 2 (T - d·v) τ + T² - d² = 0
+    fn roots(&self, x: Vector4) -> (f64, f64) {
+        let v = self.velocity / self.velocity.gamma();
+        let d = x.spatial() - self.origin.spatial();
+        let t = x.ct - self.origin.ct;
+        let a = 1.0 - v.magnitude2();
+        let b = t - d.dot(v);
+        let c = t * t - d.magnitude2();
+        let sqrt = (b * b - a * c).max(0.0).sqrt();
+        if b >= 0.0 {
+            let q = b + sqrt;
+            (if q > 0.0 { c / q } else { 0.0 }, q / a)
+        } else {
+            let q = b - sqrt;
+            (q / a, c / q)
+        }
+    }
+
+    fn point(&self, tau: f64) -> (Vector4, Vector3, Vector3) {
+        let v = self.velocity / self.velocity.gamma();
+        (
+            Vector4::from_ctv(self.origin.ct + tau, self.origin.spatial() + v * tau),
+            self.velocity,
+            Vector3::zero(),
+        )
+    }
+}
+
+impl WorldLine for InertialWorldLine {
+    fn past_intersection(
+        &self,
+        _c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        Ok(self.point(self.roots(x).0))
+    }
+
+    fn future_intersection(
+        &self,
+        _c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        Ok(self.point(self.roots(x).1))
+    }
+}
+
+/// Zero at origin
+impl ProperTime for InertialWorldLine {
+    fn proper_time(&self, _c: f64, ct: f64) -> Result<f64, WorldLineError> {
+        Ok((ct - self.origin.ct) / self.velocity.gamma())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CutOffWorldLine<W> {
     world_line: W,
@@ -43,14 +248,211 @@ impl<W> CutOffWorldLine<W> {
     }
 }
 
+/// Same clock as the inner world line, and no reading before it appeared
+impl<W: ProperTime> ProperTime for CutOffWorldLine<W> {
+    fn proper_time(&self, c: f64, ct: f64) -> Result<f64, WorldLineError> {
+        if ct < self.appeared {
+            Err(WorldLineError::SpaceLike)
+        } else {
+            self.world_line.proper_time(c, ct)
+        }
+    }
+}
+
 impl<W: WorldLine> WorldLine for CutOffWorldLine<W> {
-    fn past_intersection(&self, c: f64, x: Vector4) -> Option<(Vector4, Vector3, Vector3)> {
+    fn past_intersection(
+        &self,
+        c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
         let (x, u, a) = self.world_line.past_intersection(c, x)?;
         if x.ct < self.appeared {
-            None
+            Err(WorldLineError::SpaceLike)
         } else {
-            Some((x, u, a))
+            Ok((x, u, a))
+        }
+    }
+
+    fn past_intersections(
+        &self,
+        c: f64,
+        xs: &[Vector4],
+    ) -> Vec<Result<(Vector4, Vector3, Vector3), WorldLineError>> {
+        let mut v = self.world_line.past_intersections(c, xs);
+        for p in v.iter_mut() {
+            if matches!(p, Ok((x, _, _)) if x.ct < self.appeared) {
+                *p = Err(WorldLineError::SpaceLike);
+            }
+        }
+        v
+    }
+
+    fn future_intersection(
+        &self,
+        c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let (x, u, a) = self.world_line.future_intersection(c, x)?;
+        if x.ct < self.appeared {
+            Err(WorldLineError::SpaceLike)
+        } else {
+            Ok((x, u, a))
+        }
+    }
+}
+
+/// World line seen from another inertial frame
+///
+/// The frame of the inner world line moves with covariant velocity u,
+/// and its origin is at offset, so x = L(-u) x' + offset.
+/// Light cones are invariant, so intersections are found in the inner frame
+/// and transformed back with u and a as 4-vectors.
+#[derive(Debug, Clone)]
+pub struct BoostedWorldLine<W> {
+    world_line: W,
+    /// inner frame to this frame
+    boost: Matrix,
+    /// this frame to inner frame
+    inverse: Matrix,
+    offset: Vector4,
+}
+
+impl<W> BoostedWorldLine<W> {
+    pub fn new(world_line: W, u: Vector3) -> BoostedWorldLine<W> {
+        BoostedWorldLine::with_offset(world_line, u, Vector4::zero())
+    }
+
+    pub fn with_offset(world_line: W, u: Vector3, offset: Vector4) -> BoostedWorldLine<W> {
+        BoostedWorldLine {
+            world_line,
+            boost: Matrix::lorentz(-u),
+            inverse: Matrix::lorentz(u),
+            offset,
+        }
+    }
+
+    fn transform(&self, (x, u, a): (Vector4, Vector3, Vector3)) -> (Vector4, Vector3, Vector3) {
+        let u4 = Vector4::from_velocity(u);
+        let a4 = Vector4::from_ctv(a.dot(u) / u4.ct, a);
+        (
+            self.boost * x + self.offset,
+            (self.boost * u4).spatial(),
+            (self.boost * a4).spatial(),
+        )
+    }
+}
+
+impl<W: WorldLine> WorldLine for BoostedWorldLine<W> {
+    fn past_intersection(
+        &self,
+        c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let x = self.inverse * (x - self.offset);
+        Ok(self.transform(self.world_line.past_intersection(c, x)?))
+    }
+
+    fn past_intersections(
+        &self,
+        c: f64,
+        xs: &[Vector4],
+    ) -> Vec<Result<(Vector4, Vector3, Vector3), WorldLineError>> {
+        let xs = xs
+            .iter()
+            .map(|&x| self.inverse * (x - self.offset))
+            .collect::<Vec<_>>();
+        self.world_line
+            .past_intersections(c, &xs)
+            .into_iter()
+            .map(|p| p.map(|p| self.transform(p)))
+            .collect()
+    }
+
+    fn future_intersection(
+        &self,
+        c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let x = self.inverse * (x - self.offset);
+        Ok(self.transform(self.world_line.future_intersection(c, x)?))
+    }
+}
+
+/// World line chained from segments, each used from its start x_0 until the next one starts
+///
+/// Each segment is a whole world line that should agree with its neighbors at the joins.
+/// Since a light cone meets a time-like world line once, the segment whose own intersection
+/// falls inside its interval holds the intersection of the chained line.
+/// Intervals are half open, so at a join velocity and acceleration are taken from the later segment.
+pub struct PiecewiseWorldLine {
+    /// (start x_0, segment) in ascending order of start
+    segments: Vec<(f64, Box<dyn WorldLine>)>,
+}
+
+impl PiecewiseWorldLine {
+    /// first: the segment used before every other one
+    pub fn new(first: impl WorldLine + 'static) -> PiecewiseWorldLine {
+        PiecewiseWorldLine {
+            segments: vec![(f64::NEG_INFINITY, Box::new(first))],
+        }
+    }
+
+    /// Use segment from start, which must be after every previous start
+    pub fn push(
+        &mut self,
+        start: f64,
+        segment: impl WorldLine + 'static,
+    ) -> Result<(), WorldLineError> {
+        if start <= self.segments.last().unwrap().0 {
+            return Err(WorldLineError::InvalidParameter);
         }
+        self.segments.push((start, Box::new(segment)));
+        Ok(())
+    }
+
+    fn end(&self, i: usize) -> f64 {
+        self.segments
+            .get(i + 1)
+            .map_or(f64::INFINITY, |(start, _)| *start)
+    }
+}
+
+impl WorldLine for PiecewiseWorldLine {
+    fn past_intersection(
+        &self,
+        c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        // later segments are more likely to be seen
+        // when no segment holds the intersection, report the first failure if any
+        let mut error = WorldLineError::SpaceLike;
+        for (i, (start, segment)) in self.segments.iter().enumerate().rev() {
+            match segment.past_intersection(c, x) {
+                Ok((y, u, a)) if *start <= y.ct && y.ct < self.end(i) => return Ok((y, u, a)),
+                Ok(_) => {}
+                Err(e) if error == WorldLineError::SpaceLike => error = e,
+                Err(_) => {}
+            }
+        }
+        Err(error)
+    }
+
+    fn future_intersection(
+        &self,
+        c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        // when no segment holds the intersection, report the first failure if any
+        let mut error = WorldLineError::SpaceLike;
+        for (i, (start, segment)) in self.segments.iter().enumerate() {
+            match segment.future_intersection(c, x) {
+                Ok((y, u, a)) if *start <= y.ct && y.ct < self.end(i) => return Ok((y, u, a)),
+                Ok(_) => {}
+                Err(e) if error == WorldLineError::SpaceLike => error = e,
+                Err(_) => {}
+            }
+        }
+        Err(error)
     }
 }
 
@@ -65,16 +467,15 @@ pub struct LineOscillateWorldLine {
 }
 
 impl LineOscillateWorldLine {
-    #[allow(clippy::result_unit_err)]
     pub fn new(
         center: Vector3,
         amplitude: Vector3,
         frequency: f64,
         c: f64,
-    ) -> Result<LineOscillateWorldLine, ()> {
+    ) -> Result<LineOscillateWorldLine, WorldLineError> {
         let omega = frequency * std::f64::consts::TAU;
         if omega.abs() * amplitude.magnitude() > c {
-            Err(())
+            Err(WorldLineError::FasterThanLight)
         } else {
             Ok(LineOscillateWorldLine {
                 center,
@@ -85,17 +486,31 @@ impl LineOscillateWorldLine {
     }
 
     fn newton(&self, c: f64, x: Vector4) -> f64 {
+        let l = self.center - x.spatial();
+        self.newton_from(
+            c,
+            x,
+            x.ct - l.magnitude() - self.amplitude.magnitude() * 2.0,
+        )
+    }
+
+    /// Newton's method starting from ct, falling back to binary search
+    /// if it does not converge to the retarded root
+    fn newton_from(&self, c: f64, x: Vector4, mut ct: f64) -> f64 {
         let l = self.center - x.spatial();
         let l_len = l.magnitude();
         if l_len < f64::EPSILON * 2.0 {
             return x.ct;
         }
-        let mut ct = x.ct - l.magnitude() - self.amplitude.magnitude() * 2.0;
         for _ in 0..10 {
             let (sin, cos) = (self.omega * ct / c).sin_cos();
             let amp = l + self.amplitude * sin;
             let f = (ct - x.ct) * (ct - x.ct) - amp.magnitude2();
             if f.abs() < 1e-12 * l_len {
+                if ct > x.ct {
+                    // the advanced root
+                    break;
+                }
                 return ct;
             }
             let fp = 2.0 * (ct - x.ct - amp.dot(self.amplitude) * self.omega * cos);
@@ -134,96 +549,1042 @@ impl LineOscillateWorldLine {
     }
 }
 
+impl LineOscillateWorldLine {
+    fn point(&self, c: f64, ct: f64) -> (Vector4, Vector3, Vector3) {
+        let k = self.omega / c;
+        let (sin, cos) = (k * ct).sin_cos();
+        let v = self.amplitude * (k * cos); // dp/dx_0
+        let gamma2 = 1.0 / (1.0 - v.magnitude2());
+        // dp/dx_0 ∥ d²p/dx_0², so the covariant acceleration is γ⁴ d²p/dx_0²
+        (
+            Vector4::from_ctv(ct, self.center + self.amplitude * sin),
+            v * gamma2.sqrt(),
+            self.amplitude * (-k * k * sin * gamma2 * gamma2),
+        )
+    }
+}
+
 impl WorldLine for LineOscillateWorldLine {
-    fn past_intersection(&self, c: f64, x: Vector4) -> Option<(Vector4, Vector3, Vector3)> {
+    fn past_intersection(
+        &self,
+        c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
         let ct = self.newton(c, x);
-        let (sin, cos) = (self.omega * ct / c).sin_cos();
-        Some((
-            Vector4::from_ctv(ct, self.center + self.amplitude * sin),
-            self.amplitude * (self.omega * cos),
-            self.amplitude * (-self.omega * self.omega * sin),
-        ))
+        Ok(self.point(c, ct))
+    }
+
+    fn past_intersections(
+        &self,
+        c: f64,
+        xs: &[Vector4],
+    ) -> Vec<Result<(Vector4, Vector3, Vector3), WorldLineError>> {
+        warm_started(xs, |x, previous| {
+            let ct = match previous {
+                Some(ct) => self.newton_from(c, x, ct),
+                None => self.newton(c, x),
+            };
+            Ok((ct, self.point(c, ct)))
+        })
+    }
+
+    fn future_intersection(
+        &self,
+        c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        // the future light cone of x is the past light cone of the time-reversed x
+        // seen from the time-reversed world line, whose amplitude is flipped
+        let reversed = LineOscillateWorldLine {
+            amplitude: -self.amplitude,
+            ..self.clone()
+        };
+        let ct = -reversed.newton(c, Vector4::from_ctv(-x.ct, x.spatial()));
+        Ok(self.point(c, ct))
+    }
+}
+
+/// Zero at x_0 = 0
+///
+/// With θ = ω x_0 / c and β = |amplitude| ω / c,
+/// τ = (c / ω) ∫_0^θ sqrt(1 - β² cos² φ) dφ = (c / ω) (E(θ - π/2, β) + E(β)),
+/// where E is the elliptic integral of the second kind, which is odd and
+/// grows by 2 E(β) every π.
+impl ProperTime for LineOscillateWorldLine {
+    fn proper_time(&self, c: f64, ct: f64) -> Result<f64, WorldLineError> {
+        if self.omega == 0.0 {
+            return Ok(ct);
+        }
+        let k2 = (self.amplitude.magnitude() * self.omega / c).powi(2);
+        let theta = self.omega * ct / c;
+        let periods = (theta / std::f64::consts::PI).floor();
+        let rest = theta - periods * std::f64::consts::PI;
+        let complete = elliptic_e(std::f64::consts::FRAC_PI_2, k2);
+        let integral = periods * 2.0 * complete
+            + elliptic_e(rest - std::f64::consts::FRAC_PI_2, k2)
+            + complete;
+        Ok(integral * c / self.omega)
+    }
+}
+
+/// Incomplete elliptic integral of the second kind E(φ, k) = ∫_0^φ sqrt(1 - k² sin² θ) dθ
+/// for |φ| <= π/2, by Carlson's symmetric forms
+fn elliptic_e(phi: f64, k2: f64) -> f64 {
+    let (s, c) = phi.sin_cos();
+    if k2 >= 1.0 {
+        return s;
+    }
+    let q = 1.0 - k2 * s * s;
+    s * carlson_rf(c * c, q, 1.0) - k2 * s * s * s * carlson_rd(c * c, q, 1.0) / 3.0
+}
+
+/// Carlson's R_F(x, y, z) by duplication, at most one argument may be zero
+fn carlson_rf(mut x: f64, mut y: f64, mut z: f64) -> f64 {
+    loop {
+        let a = (x + y + z) / 3.0;
+        let (dx, dy, dz) = (1.0 - x / a, 1.0 - y / a, 1.0 - z / a);
+        if dx.abs().max(dy.abs()).max(dz.abs()) < 1e-3 {
+            let e2 = dx * dy - dz * dz;
+            let e3 = dx * dy * dz;
+            return (1.0 + (e2 / 24.0 - 0.1 - 3.0 / 44.0 * e3) * e2 + e3 / 14.0) / a.sqrt();
+        }
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * (sy + sz) + sy * sz;
+        x = 0.25 * (x + lambda);
+        y = 0.25 * (y + lambda);
+        z = 0.25 * (z + lambda);
+    }
+}
+
+/// Carlson's R_D(x, y, z) by duplication, at most one of x, y may be zero
+fn carlson_rd(mut x: f64, mut y: f64, mut z: f64) -> f64 {
+    let mut sum = 0.0;
+    let mut fac = 1.0;
+    loop {
+        let a = (x + y + 3.0 * z) / 5.0;
+        let (dx, dy, dz) = (1.0 - x / a, 1.0 - y / a, 1.0 - z / a);
+        if dx.abs().max(dy.abs()).max(dz.abs()) < 1e-3 {
+            let ea = dx * dy;
+            let eb = dz * dz;
+            let ec = ea - eb;
+            let ed = ea - 6.0 * eb;
+            let ee = ed + ec + ec;
+            let series = 1.0
+                + ed * (-3.0 / 14.0 + 9.0 / 88.0 * ed - 9.0 / 52.0 * dz * ee)
+                + dz * (ee / 6.0 + dz * (-9.0 / 22.0 * ec + 3.0 / 26.0 * dz * ea));
+            return 3.0 * sum + fac * series / (a * a.sqrt());
+        }
+        let (sx, sy, sz) = (x.sqrt(), y.sqrt(), z.sqrt());
+        let lambda = sx * (sy + sz) + sy * sz;
+        sum += fac / (sz * (z + lambda));
+        fac *= 0.25;
+        x = 0.25 * (x + lambda);
+        y = 0.25 * (y + lambda);
+        z = 0.25 * (z + lambda);
+    }
+}
+
+/// Uniformly accelerated motion (hyperbolic motion)
+///
+/// s = cτ is measured from the turning point, α = |acceleration|, n = acceleration / α
+/// x_0(s) = turning.ct + sinh(α s) / α
+/// p(s) = turning + n * (cosh(α s) - 1) / α
+#[derive(Debug, Clone)]
+pub struct HyperbolicWorldLine {
+    turning: Vector4,
+    direction: Vector3,
+    alpha: f64,
+}
+
+impl HyperbolicWorldLine {
+    /// turning: the event at which the charge is at rest
+    /// acceleration: proper acceleration, constant in the instantaneous rest frame
+    pub fn new(
+        turning: Vector4,
+        acceleration: Vector3,
+    ) -> Result<HyperbolicWorldLine, WorldLineError> {
+        let alpha = acceleration.magnitude();
+        if alpha <= f64::EPSILON {
+            Err(WorldLineError::InvalidParameter)
+        } else {
+            Ok(HyperbolicWorldLine {
+                turning,
+                direction: acceleration / alpha,
+                alpha,
+            })
+        }
+    }
+
+    /// Solve e^{α s} of the intersection with observer's past light cone.
+    ///
+    /// In the (ξ, T) plane, where ξ = 1/α + (distance along n) and T = ct - turning.ct,
+    /// the world line is ξ² - T² = 1/α² and the light cone condition becomes
+    /// (ξ - T) w² - 2αK w + (ξ + T) = 0 with w = e^{α s} and
+    /// K = (ξ² + ρ² + 1/α² - T²) / 2 (ρ is the distance from the axis).
+    /// The retarded root is always the smaller positive one.
+    fn past_exp(&self, x: Vector4) -> Result<f64, WorldLineError> {
+        let d = x.spatial() - self.turning.spatial();
+        let t = x.ct - self.turning.ct;
+        let parallel = d.dot(self.direction);
+        let rho2 = (d.magnitude2() - parallel * parallel).max(0.0);
+        let xi = parallel + 1.0 / self.alpha;
+        if xi + t <= 0.0 {
+            // behind the past horizon: the field has not arrived yet
+            return Err(WorldLineError::SpaceLike);
+        }
+        let ak = 0.5 * (self.alpha * (xi * xi + rho2 - t * t) + 1.0 / self.alpha);
+        let d = (ak * ak - (xi - t) * (xi + t)).max(0.0);
+        let denominator = ak + d.sqrt();
+        if denominator <= 0.0 {
+            return Err(WorldLineError::SpaceLike);
+        }
+        Ok((xi + t) / denominator)
+    }
+
+    fn point(&self, w: f64) -> (Vector4, Vector3, Vector3) {
+        let sinh = (w - 1.0 / w) * 0.5;
+        let cosh = (w + 1.0 / w) * 0.5;
+        let cosh_m1 = (w - 1.0) * (w - 1.0) / (2.0 * w);
+        (
+            Vector4::from_ctv(
+                self.turning.ct + sinh / self.alpha,
+                self.turning.spatial() + self.direction * (cosh_m1 / self.alpha),
+            ),
+            self.direction * sinh,
+            self.direction * (self.alpha * cosh),
+        )
+    }
+}
+
+impl WorldLine for HyperbolicWorldLine {
+    fn past_intersection(
+        &self,
+        _c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let w = self.past_exp(x)?;
+        Ok(self.point(w))
+    }
+
+    fn future_intersection(
+        &self,
+        _c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        // the world line is symmetric under s -> -s around the turning point
+        let reversed = Vector4::from_ctv(2.0 * self.turning.ct - x.ct, x.spatial());
+        let w = self.past_exp(reversed)?;
+        Ok(self.point(1.0 / w))
+    }
+}
+
+/// Zero at the turning point, s = asinh(α (x_0 - turning.ct)) / α
+impl ProperTime for HyperbolicWorldLine {
+    fn proper_time(&self, _c: f64, ct: f64) -> Result<f64, WorldLineError> {
+        Ok((self.alpha * (ct - self.turning.ct)).asinh() / self.alpha)
+    }
+}
+
+/// Uniform circular motion, optionally drifting along the axis (helical motion)
+///
+/// x_0 = ct
+/// θ(x_0) = ω x_0 / c
+/// p(x_0) = center + radius * cos θ + (axis × radius) * sin θ + axis * drift * x_0 / c
+#[derive(Debug, Clone)]
+pub struct CircularWorldLine {
+    center: Vector3,
+    radius: Vector3,
+    binormal: Vector3,
+    axis: Vector3,
+    omega: f64,
+    drift: f64,
+}
+
+impl CircularWorldLine {
+    /// radius: position relative to center at x_0 = 0, the component along axis is ignored
+    /// axis: the charge rotates counterclockwise around this vector
+    pub fn new(
+        center: Vector3,
+        radius: Vector3,
+        axis: Vector3,
+        frequency: f64,
+        c: f64,
+    ) -> Result<CircularWorldLine, WorldLineError> {
+        CircularWorldLine::helical(center, radius, axis, frequency, 0.0, c)
+    }
+
+    /// drift: velocity along the axis
+    pub fn helical(
+        center: Vector3,
+        radius: Vector3,
+        axis: Vector3,
+        frequency: f64,
+        drift: f64,
+        c: f64,
+    ) -> Result<CircularWorldLine, WorldLineError> {
+        if axis.magnitude2() <= f64::EPSILON {
+            return Err(WorldLineError::InvalidParameter);
+        }
+        let axis = axis.normalized();
+        let radius = radius - axis * radius.dot(axis);
+        let wl = CircularWorldLine {
+            center,
+            radius,
+            binormal: axis.cross(radius),
+            axis,
+            omega: frequency * std::f64::consts::TAU,
+            drift,
+        };
+        if wl.beta(c) >= 1.0 {
+            Err(WorldLineError::FasterThanLight)
+        } else {
+            Ok(wl)
+        }
+    }
+
+    fn beta(&self, c: f64) -> f64 {
+        let v = self.omega * self.radius.magnitude();
+        (v * v + self.drift * self.drift).sqrt() / c
+    }
+
+    fn point(&self, c: f64, ct: f64) -> (Vector4, Vector3, Vector3) {
+        let (p, v, a) = self.position(c, ct);
+        // a ⊥ v, so the covariant acceleration is simply γ² d²p/dx_0²
+        let beta = self.beta(c);
+        let gamma2 = 1.0 / (1.0 - beta * beta);
+        (Vector4::from_ctv(ct, p), v * gamma2.sqrt(), a * gamma2)
+    }
+
+    /// position, dp/dx_0 and d²p/dx_0²
+    fn position(&self, c: f64, ct: f64) -> (Vector3, Vector3, Vector3) {
+        let k = self.omega / c;
+        let (sin, cos) = (k * ct).sin_cos();
+        let r = self.radius * cos + self.binormal * sin;
+        (
+            self.center + r + self.axis * (self.drift * ct / c),
+            (self.binormal * cos - self.radius * sin) * k + self.axis * (self.drift / c),
+            r * (-k * k),
+        )
+    }
+}
+
+impl WorldLine for CircularWorldLine {
+    fn past_intersection(
+        &self,
+        c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let beta = self.beta(c);
+        if beta >= 1.0 {
+            return Err(WorldLineError::FasterThanLight);
+        }
+        let ct = retarded_time(x, beta, None, |ct| {
+            let (p, v, _) = self.position(c, ct);
+            (p, v)
+        })?;
+        Ok(self.point(c, ct))
+    }
+
+    fn past_intersections(
+        &self,
+        c: f64,
+        xs: &[Vector4],
+    ) -> Vec<Result<(Vector4, Vector3, Vector3), WorldLineError>> {
+        let beta = self.beta(c);
+        if beta >= 1.0 {
+            return vec![Err(WorldLineError::FasterThanLight); xs.len()];
+        }
+        warm_started(xs, |x, previous| {
+            let ct = retarded_time(x, beta, previous, |ct| {
+                let (p, v, _) = self.position(c, ct);
+                (p, v)
+            })?;
+            Ok((ct, self.point(c, ct)))
+        })
+    }
+
+    fn future_intersection(
+        &self,
+        c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let beta = self.beta(c);
+        if beta >= 1.0 {
+            return Err(WorldLineError::FasterThanLight);
+        }
+        let ct = advanced_time(x, beta, |ct| {
+            let (p, v, _) = self.position(c, ct);
+            (p, v)
+        })?;
+        Ok(self.point(c, ct))
+    }
+}
+
+type Trajectory = Box<dyn Fn(f64) -> Vector3>;
+
+/// World line given by a closure p(x_0)
+///
+/// Derivatives dp/dx_0 and d²p/dx_0² are used if given, otherwise they are
+/// taken by central differences.
+/// The speed |dp/dx_0| must never exceed max_beta < 1, which guarantees that
+/// the bracketed Newton iteration of the retarded time converges, see `retarded_time`.
+pub struct ParametricWorldLine {
+    position: Trajectory,
+    velocity: Option<Trajectory>,
+    acceleration: Option<Trajectory>,
+    max_beta: f64,
+}
+
+impl ParametricWorldLine {
+    /// position: p(x_0)
+    /// max_beta: upper bound of |dp/dx_0|, in [0, 1)
+    pub fn new(
+        position: impl Fn(f64) -> Vector3 + 'static,
+        max_beta: f64,
+    ) -> Result<ParametricWorldLine, WorldLineError> {
+        if max_beta >= 1.0 {
+            return Err(WorldLineError::FasterThanLight);
+        }
+        if max_beta.is_nan() || max_beta < 0.0 {
+            return Err(WorldLineError::InvalidParameter);
+        }
+        Ok(ParametricWorldLine {
+            position: Box::new(position),
+            velocity: None,
+            acceleration: None,
+            max_beta,
+        })
+    }
+
+    /// Analytic dp/dx_0
+    pub fn velocity(mut self, velocity: impl Fn(f64) -> Vector3 + 'static) -> Self {
+        self.velocity = Some(Box::new(velocity));
+        self
+    }
+
+    /// Analytic d²p/dx_0²
+    pub fn acceleration(mut self, acceleration: impl Fn(f64) -> Vector3 + 'static) -> Self {
+        self.acceleration = Some(Box::new(acceleration));
+        self
+    }
+
+    fn dp(&self, ct: f64) -> Vector3 {
+        match &self.velocity {
+            Some(v) => v(ct),
+            None => {
+                // error is O(h²) truncation + O(ε/h) rounding
+                let h = f64::EPSILON.cbrt() * (1.0 + ct.abs());
+                ((self.position)(ct + h) - (self.position)(ct - h)) / (2.0 * h)
+            }
+        }
+    }
+
+    fn ddp(&self, ct: f64) -> Vector3 {
+        match (&self.acceleration, &self.velocity) {
+            (Some(a), _) => a(ct),
+            (None, Some(v)) => {
+                let h = f64::EPSILON.cbrt() * (1.0 + ct.abs());
+                (v(ct + h) - v(ct - h)) / (2.0 * h)
+            }
+            (None, None) => {
+                // error is O(h²) truncation + O(ε/h²) rounding
+                let h = f64::EPSILON.powf(0.25) * (1.0 + ct.abs());
+                ((self.position)(ct + h) - (self.position)(ct) * 2.0 + (self.position)(ct - h))
+                    / (h * h)
+            }
+        }
+    }
+
+    fn point(&self, ct: f64) -> (Vector4, Vector3, Vector3) {
+        let v = self.dp(ct);
+        let dv = self.ddp(ct);
+        // u = γ v, a = du/ds = γ d(γ v)/dx_0
+        let gamma2 = 1.0 / (1.0 - v.magnitude2());
+        (
+            Vector4::from_ctv(ct, (self.position)(ct)),
+            v * gamma2.sqrt(),
+            dv * gamma2 + v * (gamma2 * gamma2 * v.dot(dv)),
+        )
+    }
+}
+
+impl WorldLine for ParametricWorldLine {
+    fn past_intersection(
+        &self,
+        _c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let ct = retarded_time(x, self.max_beta, None, |ct| {
+            ((self.position)(ct), self.dp(ct))
+        })?;
+        Ok(self.point(ct))
+    }
+
+    fn past_intersections(
+        &self,
+        _c: f64,
+        xs: &[Vector4],
+    ) -> Vec<Result<(Vector4, Vector3, Vector3), WorldLineError>> {
+        warm_started(xs, |x, previous| {
+            let ct = retarded_time(x, self.max_beta, previous, |ct| {
+                ((self.position)(ct), self.dp(ct))
+            })?;
+            Ok((ct, self.point(ct)))
+        })
+    }
+
+    fn future_intersection(
+        &self,
+        _c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let ct = advanced_time(x, self.max_beta, |ct| ((self.position)(ct), self.dp(ct)))?;
+        Ok(self.point(ct))
+    }
+}
+
+/// Solve the retarded time of the trajectory p seen from the observer x.
+///
+/// p returns the position and dp/dx_0 at x_0, and |dp/dx_0| must never exceed max_beta < 1.
+/// g(t) = x.ct - t - |x - p(t)| is then strictly decreasing with g'(t) <= -(1 - max_beta),
+/// so [x.ct - |x - p(x.ct)| / (1 - max_beta), x.ct] always brackets the single root.
+/// Newton steps are taken while they stay inside the bracket and bisection otherwise,
+/// which always converges.
+/// guess is the first Newton iterate, used only if it is inside the bracket.
+fn retarded_time(
+    x: Vector4,
+    max_beta: f64,
+    guess: Option<f64>,
+    p: impl Fn(f64) -> (Vector3, Vector3),
+) -> Result<f64, WorldLineError> {
+    let (p0, _) = p(x.ct);
+    let d0 = (x.spatial() - p0).magnitude();
+    let mut lo = x.ct - d0 / (1.0 - max_beta);
+    let mut hi = x.ct;
+    let mut t = guess.filter(|t| lo < *t && *t < hi).unwrap_or(x.ct - d0);
+    for _ in 0..200 {
+        let (pos, vel) = p(t);
+        let r = x.spatial() - pos;
+        let r_len = r.magnitude();
+        let g = x.ct - t - r_len;
+        if g.abs() <= 1e-12 * (1.0 + r_len) {
+            return Ok(t);
+        }
+        if g > 0.0 {
+            lo = t;
+        } else {
+            hi = t;
+        }
+        let gp = if r_len > 0.0 {
+            r.dot(vel) / r_len - 1.0
+        } else {
+            -1.0
+        };
+        let next = t - g / gp;
+        t = if lo < next && next < hi {
+            next
+        } else {
+            0.5 * (lo + hi)
+        };
+        if hi - lo <= f64::EPSILON * hi.abs().max(1.0) {
+            return Ok(t);
+        }
+    }
+    Err(WorldLineError::NotConverged)
+}
+
+/// Zero at x_0 = 0
+impl ProperTime for CircularWorldLine {
+    fn proper_time(&self, c: f64, ct: f64) -> Result<f64, WorldLineError> {
+        let beta = self.beta(c);
+        if beta >= 1.0 {
+            return Err(WorldLineError::FasterThanLight);
+        }
+        Ok(ct * (1.0 - beta * beta).sqrt())
+    }
+}
+
+/// Solve the intersections of xs in order, passing the x_0 of the previous solution
+/// to solve as the warm start of the next one
+fn warm_started(
+    xs: &[Vector4],
+    mut solve: impl FnMut(
+        Vector4,
+        Option<f64>,
+    ) -> Result<(f64, (Vector4, Vector3, Vector3)), WorldLineError>,
+) -> Vec<Result<(Vector4, Vector3, Vector3), WorldLineError>> {
+    let mut previous = None;
+    xs.iter()
+        .map(|&x| {
+            let (ct, p) = solve(x, previous)?;
+            previous = Some(ct);
+            Ok(p)
+        })
+        .collect()
+}
+
+/// Solve the advanced time of the trajectory p seen from the observer x,
+/// by solving the retarded time of the time-reversed trajectory.
+fn advanced_time(
+    x: Vector4,
+    max_beta: f64,
+    p: impl Fn(f64) -> (Vector3, Vector3),
+) -> Result<f64, WorldLineError> {
+    let reversed = Vector4::from_ctv(-x.ct, x.spatial());
+    let ct = retarded_time(reversed, max_beta, None, |ct| {
+        let (pos, vel) = p(-ct);
+        (pos, -vel)
+    })?;
+    Ok(-ct)
+}
+
+/// How DiscreteWorldLine connects its samples
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Straight chords, acceleration from the difference of the two nearest chords
+    #[default]
+    Linear,
+    /// Cubic Hermite curve over proper time, with tangents taken from the polynomial
+    /// through the neighboring samples.
+    /// The curve is C1, so velocity is continuous across samples and
+    /// acceleration is continuous inside each segment.
+    CubicHermite,
+}
+
+/// How DiscreteWorldLine::compact thins out old samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Compaction {
+    /// Samples within this x_0 of the newest sample are never removed
+    pub full_resolution: f64,
+    /// Older samples are thinned so that the x_0 gap between kept samples
+    /// is at most this ratio times their age
+    pub max_relative_spacing: f64,
+}
+
+impl Default for Compaction {
+    fn default() -> Self {
+        Compaction {
+            full_resolution: 16.0,
+            max_relative_spacing: 1.0 / 256.0,
+        }
     }
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct DiscreteWorldLine {
     x: Vec<Vector4>,
+    /// proper time accumulated along the chords, zero at the first pushed sample
+    tau: Vec<f64>,
+    interpolation: Interpolation,
+    /// number of samples older than the full resolution window after the last thinning
+    thinned: usize,
 }
 
 impl DiscreteWorldLine {
     pub fn new() -> DiscreteWorldLine {
-        DiscreteWorldLine { x: Vec::new() }
+        DiscreteWorldLine::with_interpolation(Interpolation::Linear)
+    }
+
+    pub fn with_interpolation(interpolation: Interpolation) -> DiscreteWorldLine {
+        DiscreteWorldLine {
+            x: Vec::new(),
+            tau: Vec::new(),
+            interpolation,
+            thinned: 0,
+        }
     }
 
     pub fn push(&mut self, x: Vector4) {
+        let tau = match (self.x.last(), self.tau.last()) {
+            (Some(&last), Some(&tau)) => tau + proper_length(last, x),
+            _ => 0.0,
+        };
         self.x.push(x);
+        self.tau.push(tau);
     }
 
     pub fn last(&self) -> Option<Vector4> {
         self.x.last().copied()
     }
 
-    fn find_future_nearest(&self, x: Vector4) -> Option<usize> {
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// Bound the memory of the history.
+    ///
+    /// Every later query must come from the causal future of `oldest`,
+    /// whose past light cone contains all of theirs, so samples before the
+    /// interpolation stencil of `oldest`'s intersection are dropped.
+    /// Samples older than `compaction.full_resolution` are thinned to a spacing
+    /// growing with their age, so only O(log(age)) of them are kept.
+    /// Thinning runs once the old samples have doubled, so the cost is amortized O(1) per push.
+    pub fn compact(&mut self, oldest: Vector4, compaction: Compaction) {
+        if let Ok(i) = self.find_future_nearest(oldest, None) {
+            // the Hermite tangent at i - 1 reaches back to i - 3
+            let drop = i.saturating_sub(3);
+            self.x.drain(..drop);
+            self.tau.drain(..drop);
+            self.thinned = self.thinned.saturating_sub(drop);
+        }
+        let Some(last) = self.last() else {
+            return;
+        };
+        let age = |y: Vector4| last.ct - y.ct;
+        let old = self
+            .x
+            .partition_point(|&y| age(y) > compaction.full_resolution);
+        if old < 2 * self.thinned + 16 {
+            return;
+        }
+
+        // walk from new to old, keeping a sample when skipping it would leave
+        // a gap wider than allowed at its age; x[0] and x[old] are always kept
+        let mut keep = vec![true; old];
+        let mut newer = self.x[old];
+        for j in (1..old).rev() {
+            if newer.ct - self.x[j - 1].ct <= compaction.max_relative_spacing * age(self.x[j]) {
+                keep[j] = false;
+            } else {
+                newer = self.x[j];
+            }
+        }
+        retain_kept(&mut self.x, &keep);
+        retain_kept(&mut self.tau, &keep);
+        self.thinned = keep.iter().filter(|&&k| k).count();
+    }
+
+    /// First sample on or outside the past light cone of x.
+    ///
+    /// The search starts around hint, the result of a nearby observer, if any.
+    fn find_future_nearest(
+        &self,
+        x: Vector4,
+        hint: Option<usize>,
+    ) -> Result<usize, WorldLineError> {
         if self.x.len() <= 2 {
-            return None;
+            return Err(WorldLineError::HistoryTooShort);
         }
         // lo = past = norm is negative
         // most post point is space-like = ng
         let mut lo = 1;
         let norm_lo = (self.x[lo] - x).lorentz_norm2();
         if norm_lo > 0.0 {
-            return None;
+            return Err(WorldLineError::SpaceLike);
         }
         // hi = future = norm is positive
         let mut hi = self.x.len() - 1;
         if self.x[hi].ct < x.ct && (self.x[hi] - x).lorentz_norm2() < 0.0 {
-            return None;
+            return Err(WorldLineError::HistoryTooShort);
+        }
+        let is_future = |i: usize| self.x[i].ct >= x.ct || (self.x[i] - x).lorentz_norm2() >= 0.0;
+        if let Some(hint) = hint {
+            // gallop from the hint to narrow [lo, hi]
+            let hint = hint.clamp(lo, hi);
+            let mut step = 1;
+            if is_future(hint) {
+                hi = hint;
+                while hi - lo >= step && is_future(hi - step) {
+                    hi -= step;
+                    step *= 2;
+                }
+                lo = lo.max(hi.saturating_sub(step) + 1);
+            } else {
+                lo = hint + 1;
+                while hi - lo > step && !is_future(lo + step) {
+                    lo += step + 1;
+                    step *= 2;
+                }
+                hi = hi.min(lo + step);
+            }
         }
         while lo < hi {
             let mid = (lo + hi) / 2;
-            if self.x[mid].ct >= x.ct || (self.x[mid] - x).lorentz_norm2() >= 0.0 {
+            if is_future(mid) {
                 hi = mid;
             } else {
                 lo = mid + 1;
             }
         }
-        Some(hi)
+        Ok(hi)
+    }
+
+    /// First sample inside the future light cone of x
+    fn find_past_nearest_future(&self, x: Vector4) -> Result<usize, WorldLineError> {
+        if self.x.len() <= 2 {
+            return Err(WorldLineError::HistoryTooShort);
+        }
+        let in_future = |y: Vector4| y.ct > x.ct && (y - x).lorentz_norm2() <= 0.0;
+        // lo = not yet in the future light cone
+        let mut lo = 1;
+        if in_future(self.x[lo]) {
+            return Err(WorldLineError::HistoryTooShort);
+        }
+        // hi = inside the future light cone
+        let mut hi = self.x.len() - 1;
+        if !in_future(self.x[hi]) {
+            return Err(WorldLineError::HistoryTooShort);
+        }
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if in_future(self.x[mid]) {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        Ok(hi)
+    }
+
+    /// dx/dτ at the i-th sample, differentiating the Lagrange polynomial through
+    /// up to five neighboring samples parametrized by the proper time along the chords
+    fn tangent(&self, i: usize) -> Vector4 {
+        let lo = i.saturating_sub(2);
+        let hi = (i + 2).min(self.x.len() - 1);
+        let mut t = [0.0; 5];
+        for j in lo + 1..=hi {
+            t[j - lo] = t[j - lo - 1] + proper_length(self.x[j - 1], self.x[j]);
+        }
+        let t = &t[..=hi - lo];
+        if t.windows(2).any(|w| w[1] <= w[0]) {
+            // repeated samples
+            let h = proper_length(self.x[lo], self.x[hi]);
+            return if h > 0.0 {
+                (self.x[hi] - self.x[lo]) / h
+            } else {
+                Vector4::new(0.0, 0.0, 0.0, 1.0)
+            };
+        }
+        let k = i - lo;
+        let mut d = Vector4::zero();
+        for (j, &tj) in t.iter().enumerate() {
+            let w = if j == k {
+                (0..t.len())
+                    .filter(|&m| m != k)
+                    .map(|m| 1.0 / (t[k] - t[m]))
+                    .sum::<f64>()
+            } else {
+                (0..t.len())
+                    .filter(|&m| m != j && m != k)
+                    .map(|m| (t[k] - t[m]) / (tj - t[m]))
+                    .product::<f64>()
+                    / (tj - t[k])
+            };
+            d += self.x[lo + j] * w;
+        }
+        d
+    }
+
+    /// Intersect the Hermite curve between the (i-1)-th and i-th samples
+    /// with the past (sign = 1) or future (sign = -1) light cone of x.
+    ///
+    /// g(λ) = x.ct - X_0(λ) - sign |x - X(λ)| is positive at λ = 0 and non-positive at λ = 1,
+    /// so safeguarded Newton steps on [0, 1] always converge.
+    fn hermite_intersection(
+        &self,
+        i: usize,
+        x: Vector4,
+        sign: f64,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let x1 = self.x[i - 1];
+        let x2 = self.x[i];
+        let h = proper_length(x1, x2);
+        let m1 = self.tangent(i - 1) * h;
+        let m2 = self.tangent(i) * h;
+        let curve = |l: f64| {
+            let l2 = l * l;
+            let l3 = l2 * l;
+            let p = x1 * (2.0 * l3 - 3.0 * l2 + 1.0)
+                + m1 * (l3 - 2.0 * l2 + l)
+                + x2 * (-2.0 * l3 + 3.0 * l2)
+                + m2 * (l3 - l2);
+            let dp = x1 * (6.0 * l2 - 6.0 * l)
+                + m1 * (3.0 * l2 - 4.0 * l + 1.0)
+                + x2 * (-6.0 * l2 + 6.0 * l)
+                + m2 * (3.0 * l2 - 2.0 * l);
+            let ddp = x1 * (12.0 * l - 6.0)
+                + m1 * (6.0 * l - 4.0)
+                + x2 * (-12.0 * l + 6.0)
+                + m2 * (6.0 * l - 2.0);
+            (p, dp, ddp)
+        };
+
+        let (mut lo, mut hi) = (0.0, 1.0);
+        let mut l = 0.5;
+        let mut converged = false;
+        for _ in 0..100 {
+            let (p, dp, _) = curve(l);
+            let r = x.spatial() - p.spatial();
+            let r_len = r.magnitude();
+            let g = x.ct - p.ct - sign * r_len;
+            if g.abs() <= 1e-12 * (1.0 + r_len) {
+                converged = true;
+                break;
+            }
+            if g > 0.0 {
+                lo = l;
+            } else {
+                hi = l;
+            }
+            let gp = if r_len > 0.0 {
+                sign * r.dot(dp.spatial()) / r_len - dp.ct
+            } else {
+                -dp.ct
+            };
+            let next = l - g / gp;
+            l = if lo < next && next < hi {
+                next
+            } else {
+                0.5 * (lo + hi)
+            };
+            if hi - lo <= f64::EPSILON {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(WorldLineError::NotConverged);
+        }
+
+        let (p, dp, ddp) = curve(l);
+        // u = X' / N, du/dλ = X'' / N + X' (X'·X'') / N³ where N = dτ/dλ
+        let n = (-dp.lorentz_norm2()).max(f64::EPSILON).sqrt();
+        let u = dp / n;
+        let du = ddp / n + dp * (dp.lorentz_dot(ddp) / (n * n * n));
+        Ok((p, u.spatial(), du.spatial() / n))
     }
-}
 
-impl WorldLine for DiscreteWorldLine {
-    fn past_intersection(&self, _c: f64, x: Vector4) -> Option<(Vector4, Vector3, Vector3)> {
-        let i = self.find_future_nearest(x)?;
-        let x0 = self.x[i - 2]; // next to nearest past
-        let x1 = self.x[i - 1]; // nearest past
-        let x2 = self.x[i]; // most future
+    /// Intersection with the past light cone of x between the (i-1)-th and i-th samples
+    fn past_intersection_at(
+        &self,
+        i: usize,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        if self.interpolation == Interpolation::CubicHermite {
+            return self.hermite_intersection(i, x, 1.0);
+        }
+        let x1 = self.x[i - 1];
+        let x2 = self.x[i];
 
         let a = -(x2 - x1).lorentz_norm2();
         let b = -(x2 - x1).lorentz_dot(x - x1);
         let c = -(x - x1).lorentz_norm2();
         let lambda = c / (b + (b * b - a * c).sqrt());
 
+        Ok(self.linear_point(i, lambda))
+    }
+
+    /// Point at λ on the chord between the (i-1)-th and i-th samples,
+    /// with velocity of the chord and acceleration from the previous chord
+    fn linear_point(&self, i: usize, lambda: f64) -> (Vector4, Vector3, Vector3) {
+        let x0 = self.x[i - 2]; // next to nearest past
+        let x1 = self.x[i - 1]; // nearest past
+        let x2 = self.x[i]; // most future
+
         let tau0 = (-(x1 - x0).lorentz_norm2()).sqrt();
         let tau1 = (-(x2 - x1).lorentz_norm2()).sqrt();
         let u0 = (x1 - x0).spatial() / tau0;
         let u1 = (x2 - x1).spatial() / tau1;
         let acc = (u1 - u0) * (2.0 / (tau0 + tau1));
 
-        Some((x1 * (1.0 - lambda) + x2 * lambda, u1, acc))
+        (x1 * (1.0 - lambda) + x2 * lambda, u1, acc)
+    }
+}
+
+/// Keep v[i] if keep[i], or if i is beyond keep
+fn retain_kept<T>(v: &mut Vec<T>, keep: &[bool]) {
+    let mut i = 0;
+    v.retain(|_| {
+        i += 1;
+        keep.get(i - 1).copied().unwrap_or(true)
+    });
+}
+
+fn proper_length(x0: Vector4, x1: Vector4) -> f64 {
+    (-(x1 - x0).lorentz_norm2()).max(0.0).sqrt()
+}
+
+/// Zero at the first pushed sample, linear in x_0 between samples.
+/// Compaction keeps the readings of the remaining samples.
+impl ProperTime for DiscreteWorldLine {
+    fn proper_time(&self, _c: f64, ct: f64) -> Result<f64, WorldLineError> {
+        let i = self.x.partition_point(|y| y.ct < ct);
+        if i == self.x.len() {
+            return Err(WorldLineError::HistoryTooShort);
+        }
+        if self.x[i].ct == ct {
+            return Ok(self.tau[i]);
+        }
+        if i == 0 {
+            return Err(WorldLineError::HistoryTooShort);
+        }
+        let (x0, x1) = (self.x[i - 1].ct, self.x[i].ct);
+        let lambda = (ct - x0) / (x1 - x0);
+        Ok(self.tau[i - 1] * (1.0 - lambda) + self.tau[i] * lambda)
+    }
+}
+
+impl WorldLine for DiscreteWorldLine {
+    fn past_intersection(
+        &self,
+        _c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let i = self.find_future_nearest(x, None)?;
+        self.past_intersection_at(i, x)
+    }
+
+    fn past_intersections(
+        &self,
+        _c: f64,
+        xs: &[Vector4],
+    ) -> Vec<Result<(Vector4, Vector3, Vector3), WorldLineError>> {
+        let mut hint = None;
+        xs.iter()
+            .map(|&x| {
+                let i = self.find_future_nearest(x, hint)?;
+                hint = Some(i);
+                self.past_intersection_at(i, x)
+            })
+            .collect()
+    }
+
+    fn future_intersection(
+        &self,
+        _c: f64,
+        x: Vector4,
+    ) -> Result<(Vector4, Vector3, Vector3), WorldLineError> {
+        let i = self.find_past_nearest_future(x)?;
+        if self.interpolation == Interpolation::CubicHermite {
+            return self.hermite_intersection(i, x, -1.0);
+        }
+        let x1 = self.x[i - 1];
+        let x2 = self.x[i];
+
+        // the larger root of the same quadratic as past_intersection
+        let a = -(x2 - x1).lorentz_norm2();
+        let b = -(x2 - x1).lorentz_dot(x - x1);
+        let c = -(x - x1).lorentz_norm2();
+        let lambda = (b + (b * b - a * c).sqrt()) / a;
+
+        Ok(self.linear_point(i, lambda))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::PhaseSpace;
     use approx::assert_relative_eq;
     use rand::Rng;
     use rand_pcg::Mcg128Xsl64;
 
     #[test]
     fn discrete_world_line_zero() {
-        use crate::PhaseSpace;
         let mut wl = DiscreteWorldLine::new();
         let mut p = PhaseSpace::new(Vector3::zero(), Vector4::zero());
         for _ in 0..100 {
@@ -277,7 +1638,7 @@ mod tests {
         wl.push(Vector4::from_ctv(2.0, x));
         assert_eq!(
             wl.past_intersection(1.0, Vector4::new(0.0, 0.0, 0.0, 4.9999)),
-            None
+            Err(WorldLineError::SpaceLike)
         );
         assert_eq!(
             wl.past_intersection(1.0, Vector4::new(0.0, 0.0, 0.0, 5.0009765625))
@@ -293,10 +1654,155 @@ mod tests {
         );
         assert_eq!(
             wl.past_intersection(1.0, Vector4::new(0.0, 0.0, 0.0, 7.0001)),
-            None
+            Err(WorldLineError::HistoryTooShort)
         );
     }
 
+    #[test]
+    fn discrete_world_line_future() {
+        for interpolation in [Interpolation::Linear, Interpolation::CubicHermite] {
+            let mut wl = DiscreteWorldLine::with_interpolation(interpolation);
+            let x = Vector3::new(3.0, 0.0, 4.0);
+            for t in -1..=2 {
+                wl.push(Vector4::from_ctv(t as f64, x));
+            }
+            assert_eq!(
+                wl.future_intersection(1.0, Vector4::new(0.0, 0.0, 0.0, -5.0001)),
+                Err(WorldLineError::HistoryTooShort)
+            );
+            assert_relative_eq!(
+                wl.future_intersection(1.0, Vector4::new(0.0, 0.0, 0.0, -4.5))
+                    .unwrap()
+                    .0,
+                Vector4::from_ctv(0.5, x),
+            );
+            assert_relative_eq!(
+                wl.future_intersection(1.0, Vector4::new(0.0, 0.0, 0.0, -3.0001))
+                    .unwrap()
+                    .0,
+                Vector4::from_ctv(1.9999, x),
+                epsilon = 1e-12,
+            );
+            assert_eq!(
+                wl.future_intersection(1.0, Vector4::new(0.0, 0.0, 0.0, -2.9999)),
+                Err(WorldLineError::HistoryTooShort)
+            );
+        }
+    }
+
+    #[test]
+    fn discrete_world_line_hermite_straight() {
+        let mut wl = DiscreteWorldLine::with_interpolation(Interpolation::CubicHermite);
+        let u = Vector3::new(0.3, -0.4, 0.0);
+        let mut p = PhaseSpace::new(u, Vector4::zero());
+        for _ in 0..100 {
+            wl.push(p.position);
+            p.tick(0.5, Vector3::zero());
+        }
+        let x = Vector4::from_ctv(20.0, Vector3::new(3.0, 2.0, 1.0));
+        let (px, pu, pa) = wl.past_intersection(1.0, x).unwrap();
+        assert_relative_eq!((x - px).lorentz_norm2(), 0.0, epsilon = 1e-10);
+        assert_relative_eq!(px.spatial(), u * (px.ct / u.gamma()), epsilon = 1e-10);
+        assert_relative_eq!(pu, u, epsilon = 1e-10);
+        assert_relative_eq!(pa, Vector3::zero(), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn discrete_world_line_hermite_oscillate() {
+        let center = Vector3::new(1.0, 2.0, 3.0);
+        let amp = Vector3::new(1.0, 0.5, 0.0);
+        let omega = 0.6 / amp.magnitude();
+        let analytic =
+            LineOscillateWorldLine::new(center, amp, omega / std::f64::consts::TAU, 1.0).unwrap();
+        let mut linear = DiscreteWorldLine::new();
+        let mut hermite = DiscreteWorldLine::with_interpolation(Interpolation::CubicHermite);
+        for i in -400..=400 {
+            let ct = i as f64 * 0.25;
+            let x = Vector4::from_ctv(ct, center + amp * (omega * ct).sin());
+            linear.push(x);
+            hermite.push(x);
+        }
+
+        let mut rng = Mcg128Xsl64::new(1);
+        let mut linear_error = (0.0f64, 0.0f64, 0.0f64);
+        let mut hermite_error = (0.0f64, 0.0f64, 0.0f64);
+        for _ in 0..1000 {
+            let x = Vector4::new(
+                rng.gen_range(-30.0..30.0),
+                rng.gen_range(-30.0..30.0),
+                rng.gen_range(-30.0..30.0),
+                rng.gen_range(0.0..40.0),
+            );
+            let (ex, _, _) = analytic.past_intersection(1.0, x).unwrap();
+            let (sin, cos) = (omega * ex.ct).sin_cos();
+            let v = amp * (omega * cos);
+            let dv = amp * (-omega * omega * sin);
+            let gamma = 1.0 / (1.0 - v.magnitude2()).sqrt();
+            let eu = v * gamma;
+            let ea = dv * (gamma * gamma) + v * (gamma.powi(4) * v.dot(dv));
+            for (wl, error) in [(&linear, &mut linear_error), (&hermite, &mut hermite_error)] {
+                let (px, pu, pa) = wl.past_intersection(1.0, x).unwrap();
+                error.0 = error.0.max((px - ex).spatial().magnitude());
+                error.1 = error.1.max((pu - eu).magnitude());
+                error.2 = error.2.max((pa - ea).magnitude());
+            }
+        }
+        assert!(hermite_error.0 < 1e-4, "{:?}", hermite_error);
+        assert!(hermite_error.1 < 1e-3, "{:?}", hermite_error);
+        assert!(hermite_error.2 < 1e-2, "{:?}", hermite_error);
+        assert!(hermite_error.0 < linear_error.0 * 0.1);
+        assert!(hermite_error.1 < linear_error.1 * 0.1);
+        assert!(hermite_error.2 < linear_error.2 * 0.1);
+    }
+
+    #[test]
+    fn discrete_world_line_compact() {
+        let center = Vector3::new(1.0, 2.0, 3.0);
+        let amp = Vector3::new(1.0, 0.5, 0.0);
+        let omega = 0.6 / amp.magnitude();
+        let analytic =
+            LineOscillateWorldLine::new(center, amp, omega / std::f64::consts::TAU, 1.0).unwrap();
+        let mut full = DiscreteWorldLine::with_interpolation(Interpolation::CubicHermite);
+        let mut compacted = DiscreteWorldLine::with_interpolation(Interpolation::CubicHermite);
+        // observers stay within 30 of the center, so their intersections are at most 60 older
+        let oldest = |ct: f64| Vector4::from_ctv(ct - 60.0, center);
+        for i in -16000..=1600 {
+            let ct = i as f64 / 16.0;
+            let x = Vector4::from_ctv(ct, center + amp * (omega * ct).sin());
+            full.push(x);
+            compacted.push(x);
+            if i % 16 == 0 {
+                compacted.compact(oldest(ct), Compaction::default());
+            }
+        }
+        assert_eq!(full.len(), 17601);
+        assert!(compacted.len() < 800, "{}", compacted.len());
+
+        let mut rng = Mcg128Xsl64::new(1);
+        for _ in 0..1000 {
+            let dx = Vector3::new(
+                rng.gen_range(-17.0..17.0),
+                rng.gen_range(-17.0..17.0),
+                rng.gen_range(-17.0..17.0),
+            );
+            let x = Vector4::from_ctv(rng.gen_range(dx.magnitude() + 40.0..100.0), center + dx);
+            let (ex, eu, _) = analytic.past_intersection(1.0, x).unwrap();
+            let (fx, fu, fa) = full.past_intersection(1.0, x).unwrap();
+            let (px, pu, pa) = compacted.past_intersection(1.0, x).unwrap();
+            if 100.0 - fx.ct < Compaction::default().full_resolution {
+                // untouched near the present
+                assert_eq!((px, pu, pa), (fx, fu, fa));
+            }
+            assert_relative_eq!(
+                compacted.past_proper_time(1.0, x).unwrap(),
+                full.past_proper_time(1.0, x).unwrap(),
+                max_relative = 1e-6
+            );
+            assert!((px - ex).spatial().magnitude() < 1e-4);
+            assert!((pu - eu).magnitude() < 1e-3);
+        }
+    }
+
     #[test]
     fn static_world_line() {
         let wl = StaticWorldLine::new(Vector3::new(1.0, 2.0, 3.0));
@@ -306,6 +1812,193 @@ mod tests {
         assert_relative_eq!(x, Vector4::from_ctv(-4.0, Vector3::new(1.0, 2.0, 3.0)));
         assert_eq!(u, Vector3::zero());
         assert_eq!(a, Vector3::zero());
+        let (x, _, _) = wl
+            .future_intersection(1.0, Vector4::from_ctv(1.0, Vector3::new(-2.0, 2.0, -1.0)))
+            .unwrap();
+        assert_relative_eq!(x, Vector4::from_ctv(6.0, Vector3::new(1.0, 2.0, 3.0)));
+    }
+
+    #[test]
+    fn inertial_world_line() {
+        let mut rng = Mcg128Xsl64::new(1);
+        for _ in 0..100000 {
+            let origin = Vector4::new(
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+            );
+            let u = Vector3::new(
+                rng.gen_range(-3.0..3.0),
+                rng.gen_range(-3.0..3.0),
+                rng.gen_range(-3.0..3.0),
+            );
+            let wl = InertialWorldLine::new(origin, u);
+            let x = Vector4::new(
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+            );
+            let (px, pu, pa) = wl.past_intersection(1.0, x).unwrap();
+            let (fx, _, _) = wl.future_intersection(1.0, x).unwrap();
+            assert!(px.ct <= x.ct && x.ct <= fx.ct);
+            for y in [px, fx] {
+                let l = x - y;
+                assert!(l.lorentz_norm2().abs() < 1e-8 * (1.0 + l.ct * l.ct));
+                // on the world line
+                let d = y - origin;
+                assert_relative_eq!(
+                    d.spatial(),
+                    u * (d.ct / u.gamma()),
+                    epsilon = 1e-9 * (1.0 + d.ct.abs())
+                );
+            }
+            assert_eq!(pu, u);
+            assert_eq!(pa, Vector3::zero());
+        }
+    }
+
+    #[test]
+    fn piecewise_world_line() {
+        // at rest, accelerated along x until u = 1 at x_0 = 2, then coasting
+        let alpha = 0.5;
+        let end = Vector4::new((2.0f64.sqrt() - 1.0) / alpha, 0.0, 0.0, 2.0);
+        let mut wl = PiecewiseWorldLine::new(StaticWorldLine::new(Vector3::zero()));
+        wl.push(
+            0.0,
+            HyperbolicWorldLine::new(Vector4::zero(), Vector3::new(alpha, 0.0, 0.0)).unwrap(),
+        )
+        .unwrap();
+        wl.push(
+            2.0,
+            InertialWorldLine::new(end, Vector3::new(1.0, 0.0, 0.0)),
+        )
+        .unwrap();
+        assert!(wl.push(1.0, StaticWorldLine::new(Vector3::zero())).is_err());
+
+        let mut rng = Mcg128Xsl64::new(1);
+        for _ in 0..10000 {
+            let x = Vector4::new(
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-20.0..20.0),
+            );
+            for (y, u, a) in [
+                wl.past_intersection(1.0, x).unwrap(),
+                wl.future_intersection(1.0, x).unwrap(),
+            ] {
+                let l = x - y;
+                assert!(l.lorentz_norm2().abs() < 1e-8 * (1.0 + l.ct * l.ct));
+                if y.ct < 0.0 {
+                    assert_eq!(y.spatial(), Vector3::zero());
+                    assert_eq!(u, Vector3::zero());
+                } else if y.ct < 2.0 {
+                    assert_relative_eq!(u, Vector3::new(alpha * y.ct, 0.0, 0.0), epsilon = 1e-9);
+                    assert_relative_eq!(a.x, alpha * u.gamma(), epsilon = 1e-9);
+                } else {
+                    assert_eq!(u, Vector3::new(1.0, 0.0, 0.0));
+                    assert_eq!(a, Vector3::zero());
+                }
+            }
+        }
+
+        // continuous across the join
+        let x = end + Vector4::new(0.0, 5.0, 0.0, 5.0);
+        let (before, u_before, _) = wl
+            .past_intersection(1.0, x - Vector4::new(0.0, 0.0, 0.0, 1e-9))
+            .unwrap();
+        let (after, u_after, _) = wl
+            .past_intersection(1.0, x + Vector4::new(0.0, 0.0, 0.0, 1e-9))
+            .unwrap();
+        assert_relative_eq!(before, end, epsilon = 1e-8);
+        assert_relative_eq!(after, end, epsilon = 1e-8);
+        assert_relative_eq!(u_before, u_after, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn boosted_world_line() {
+        let mut rng = Mcg128Xsl64::new(1);
+        for _ in 0..10000 {
+            let turning = Vector4::new(
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+            );
+            let acc = Vector3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            );
+            let u = Vector3::new(
+                rng.gen_range(-2.0..2.0),
+                rng.gen_range(-2.0..2.0),
+                rng.gen_range(-2.0..2.0),
+            );
+            let offset = Vector4::new(
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+            );
+            let wl = HyperbolicWorldLine::new(turning, acc).unwrap();
+            let boosted = BoostedWorldLine::with_offset(wl.clone(), u, offset);
+            let back =
+                BoostedWorldLine::with_offset(boosted.clone(), -u, -(Matrix::lorentz(u) * offset));
+
+            let x = Vector4::new(
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(0.0..20.0),
+            );
+            let (Ok(expected), Ok(actual)) =
+                (wl.past_intersection(1.0, x), back.past_intersection(1.0, x))
+            else {
+                continue;
+            };
+            let eps = 1e-8 * (1.0 + expected.1.magnitude2());
+            assert_relative_eq!(expected.0, actual.0, epsilon = eps);
+            assert_relative_eq!(expected.1, actual.1, epsilon = eps);
+            assert_relative_eq!(
+                expected.2,
+                actual.2,
+                epsilon = eps * (1.0 + expected.2.magnitude())
+            );
+
+            // the boosted line is a world line in the new frame
+            if let Ok((y, bu, ba)) = boosted.past_intersection(1.0, x) {
+                let l = x - y;
+                assert!(l.ct >= 0.0);
+                assert!(l.lorentz_norm2().abs() < 1e-8 * (1.0 + l.ct * l.ct));
+                let ba = Vector4::from_ctv(ba.dot(bu) / bu.gamma(), ba);
+                assert_relative_eq!(
+                    ba.lorentz_norm2(),
+                    acc.magnitude2(),
+                    epsilon = 1e-8 * (1.0 + ba.ct * ba.ct)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn boosted_static_world_line() {
+        let u = Vector3::new(0.3, -0.4, 1.2);
+        let origin = Vector4::new(1.0, 2.0, 3.0, 0.0);
+        let boosted =
+            BoostedWorldLine::with_offset(StaticWorldLine::new(Vector3::zero()), u, origin);
+        let inertial = InertialWorldLine::new(origin, u);
+        let x = Vector4::new(-5.0, 4.0, 2.0, 7.0);
+        let (bx, bu, ba) = boosted.past_intersection(1.0, x).unwrap();
+        let (ix, iu, _) = inertial.past_intersection(1.0, x).unwrap();
+        assert_relative_eq!(bx, ix, epsilon = 1e-12);
+        assert_relative_eq!(bu, iu, epsilon = 1e-12);
+        assert_relative_eq!(ba, Vector3::zero());
+        let (bx, _, _) = boosted.future_intersection(1.0, x).unwrap();
+        let (ix, _, _) = inertial.future_intersection(1.0, x).unwrap();
+        assert_relative_eq!(bx, ix, epsilon = 1e-12);
     }
 
     #[test]
@@ -317,7 +2010,7 @@ mod tests {
             .unwrap();
         assert_relative_eq!(x, Vector4::from_ctv(-0.5, Vector3::new(1.0, 2.0, 3.0)));
         let p = wl.past_intersection(1.0, Vector4::from_ctv(3.5, Vector3::new(-1.5, 2.0, -1.0)));
-        assert!(p.is_none());
+        assert_eq!(p, Err(WorldLineError::SpaceLike));
     }
 
     #[test]
@@ -355,6 +2048,529 @@ mod tests {
                 (x - px).lorentz_norm2(),
                 pu.magnitude(),
             );
+            let (fx, _, _) = wl.future_intersection(1.0, x).unwrap();
+            assert!(fx.ct >= x.ct);
+            assert!((x - fx).lorentz_norm2().abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn line_oscillate_covariant_velocity() {
+        // u = dp/ds = γ dp/dx_0 and a = du/ds, not the derivatives by x_0 or t
+        let amp = Vector3::new(1.0, 0.5, 0.0);
+        let (frequency, c) = (0.5, 4.0);
+        let wl =
+            LineOscillateWorldLine::new(Vector3::new(1.0, 2.0, 3.0), amp, frequency, c).unwrap();
+        let h = 1e-5;
+        for i in 0..100 {
+            let ct = i as f64 * 0.37;
+            let (_, u, a) = wl.point(c, ct);
+            let (prev, next) = (wl.point(c, ct - h), wl.point(c, ct + h));
+            let v = (next.0 - prev.0).spatial() / (2.0 * h);
+            let gamma = 1.0 / (1.0 - v.magnitude2()).sqrt();
+            assert_relative_eq!(u, v * gamma, epsilon = 1e-8);
+            assert_relative_eq!(a, (next.1 - prev.1) / (2.0 * h) * gamma, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn hyperbolic_world_line_on_axis() {
+        let wl = HyperbolicWorldLine::new(Vector4::zero(), Vector3::new(0.0, 0.0, 1.0)).unwrap();
+        // the observer at the turning point sees the turning point itself
+        let (x, u, a) = wl.past_intersection(1.0, Vector4::zero()).unwrap();
+        assert_relative_eq!(x, Vector4::zero());
+        assert_relative_eq!(u, Vector3::zero());
+        assert_relative_eq!(a, Vector3::new(0.0, 0.0, 1.0));
+        // behind the past horizon
+        assert_eq!(
+            wl.past_intersection(1.0, Vector4::new(0.0, 0.0, -2.0, 0.5)),
+            Err(WorldLineError::SpaceLike)
+        );
+        // s = ln(2): ct = 3/4, z = 1/4, u = 3/4, a = 5/4
+        let (x, u, a) = wl
+            .past_intersection(1.0, Vector4::new(0.0, 0.0, 1.0, 1.5))
+            .unwrap();
+        assert_relative_eq!(x, Vector4::new(0.0, 0.0, 0.25, 0.75));
+        assert_relative_eq!(u, Vector3::new(0.0, 0.0, 0.75));
+        assert_relative_eq!(a, Vector3::new(0.0, 0.0, 1.25));
+    }
+
+    #[test]
+    fn hyperbolic_world_line() {
+        let mut rng = Mcg128Xsl64::new(1);
+        for _ in 0..100000 {
+            let turning = Vector4::new(
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+            );
+            let acc = Vector3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            );
+            let wl = HyperbolicWorldLine::new(turning, acc).unwrap();
+
+            let x = Vector4::new(
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+            );
+            if let Ok((fx, _, _)) = wl.future_intersection(1.0, x) {
+                let l = fx - x;
+                assert!(l.ct >= 0.0);
+                assert!(l.lorentz_norm2().abs() < 1e-8 * (1.0 + l.ct * l.ct));
+            } else {
+                let d = x - turning;
+                assert!(-d.ct + d.spatial().dot(acc.normalized()) + 1.0 / acc.magnitude() <= 0.0);
+            }
+            let Ok((px, pu, pa)) = wl.past_intersection(1.0, x) else {
+                let d = x - turning;
+                assert!(d.ct + d.spatial().dot(acc.normalized()) + 1.0 / acc.magnitude() <= 0.0);
+                continue;
+            };
+            let l = x - px;
+            assert!(l.ct >= 0.0);
+            assert!(
+                l.lorentz_norm2().abs() < 1e-8 * (1.0 + l.ct * l.ct),
+                "x={:?}\npx={:?}\nnorm={}",
+                x - turning,
+                px - turning,
+                l.lorentz_norm2(),
+            );
+            // u and a are tangent to the world line and the proper acceleration is constant
+            assert_relative_eq!(
+                px.ct - turning.ct,
+                pu.dot(acc.normalized()) / acc.magnitude(),
+                epsilon = 1e-8 * (1.0 + (px.ct - turning.ct).abs())
+            );
+            let pa = Vector4::new(pa.x, pa.y, pa.z, pa.dot(pu) / pu.gamma());
+            assert_relative_eq!(
+                pa.lorentz_norm2(),
+                acc.magnitude2(),
+                epsilon = 1e-10 * (1.0 + pa.ct * pa.ct)
+            );
+        }
+    }
+
+    #[test]
+    fn circular_world_line_center() {
+        let wl = CircularWorldLine::new(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            0.125,
+            4.0,
+        )
+        .unwrap();
+        // the charge is always 2 away from the center, θ = π/2 at x_0 = 8
+        let (x, u, a) = wl
+            .past_intersection(4.0, Vector4::new(1.0, 2.0, 3.0, 10.0))
+            .unwrap();
+        assert_relative_eq!(x, Vector4::new(1.0, 4.0, 3.0, 8.0));
+        let beta = std::f64::consts::PI / 8.0;
+        let gamma = 1.0 / (1.0 - beta * beta).sqrt();
+        assert_relative_eq!(u, Vector3::new(-gamma * beta, 0.0, 0.0), epsilon = 1e-12);
+        assert_relative_eq!(
+            a,
+            Vector3::new(0.0, -gamma * gamma * beta * beta / 2.0, 0.0),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn circular_world_line_future() {
+        let wl = CircularWorldLine::new(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            0.125,
+            4.0,
+        )
+        .unwrap();
+        // θ = 3π/4 at x_0 = 12
+        let (x, _, _) = wl
+            .future_intersection(4.0, Vector4::new(1.0, 2.0, 3.0, 10.0))
+            .unwrap();
+        let s = std::f64::consts::SQRT_2;
+        assert_relative_eq!(
+            x,
+            Vector4::new(1.0 - s, 2.0 + s, 3.0, 12.0),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn parametric_world_line() {
+        let center = Vector3::new(1.0, 2.0, 3.0);
+        let radius = Vector3::new(2.0, 0.0, 0.0);
+        let axis = Vector3::new(0.0, 0.0, 1.0);
+        let k = 0.4;
+        let circular =
+            CircularWorldLine::new(center, radius, axis, k / std::f64::consts::TAU, 1.0).unwrap();
+        let p = move |ct: f64| {
+            let (sin, cos) = (k * ct).sin_cos();
+            center + Vector3::new(2.0 * cos, 2.0 * sin, 0.0)
+        };
+        let v = move |ct: f64| {
+            let (sin, cos) = (k * ct).sin_cos();
+            Vector3::new(-2.0 * k * sin, 2.0 * k * cos, 0.0)
+        };
+        let a = move |ct: f64| -(p(ct) - center) * (k * k);
+        let analytic = ParametricWorldLine::new(p, 0.8)
+            .unwrap()
+            .velocity(v)
+            .acceleration(a);
+        let numeric = ParametricWorldLine::new(p, 0.8).unwrap();
+        assert!(ParametricWorldLine::new(p, 1.0).is_err());
+
+        let mut rng = Mcg128Xsl64::new(1);
+        for _ in 0..10000 {
+            let x = Vector4::new(
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+            );
+            let (ex, eu, ea) = circular.past_intersection(1.0, x).unwrap();
+            let (px, pu, pa) = analytic.past_intersection(1.0, x).unwrap();
+            assert_relative_eq!(px, ex, epsilon = 1e-8);
+            assert_relative_eq!(pu, eu, epsilon = 1e-8);
+            assert_relative_eq!(pa, ea, epsilon = 1e-8);
+            let (px, pu, pa) = numeric.past_intersection(1.0, x).unwrap();
+            assert_relative_eq!(px, ex, epsilon = 1e-8);
+            assert_relative_eq!(pu, eu, epsilon = 1e-6);
+            assert_relative_eq!(pa, ea, epsilon = 1e-4);
+
+            let (ex, _, _) = circular.future_intersection(1.0, x).unwrap();
+            let (px, _, _) = numeric.future_intersection(1.0, x).unwrap();
+            assert_relative_eq!(px, ex, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn proper_time_rate() {
+        let world_lines: Vec<Box<dyn ProperTime>> = vec![
+            Box::new(StaticWorldLine::new(Vector3::new(1.0, 2.0, 3.0))),
+            Box::new(InertialWorldLine::new(
+                Vector4::new(1.0, 2.0, 3.0, 4.0),
+                Vector3::new(0.5, -1.0, 2.0),
+            )),
+            Box::new(
+                HyperbolicWorldLine::new(
+                    Vector4::new(1.0, 2.0, 3.0, 4.0),
+                    Vector3::new(0.1, 0.2, 0.0),
+                )
+                .unwrap(),
+            ),
+            Box::new(
+                CircularWorldLine::helical(
+                    Vector3::new(1.0, 2.0, 3.0),
+                    Vector3::new(2.0, 0.0, 0.0),
+                    Vector3::new(0.0, 1.0, 1.0),
+                    0.05,
+                    0.3,
+                    1.0,
+                )
+                .unwrap(),
+            ),
+        ];
+        let mut rng = Mcg128Xsl64::new(1);
+        for wl in world_lines {
+            for _ in 0..1000 {
+                let x = Vector4::new(
+                    rng.gen_range(-10.0..10.0),
+                    rng.gen_range(-10.0..10.0),
+                    rng.gen_range(-10.0..10.0),
+                    rng.gen_range(0.0..20.0),
+                );
+                let Ok((y, u, _)) = wl.past_intersection(1.0, x) else {
+                    continue;
+                };
+                let h = 1e-5;
+                let rate = wl.proper_time_between(1.0, y.ct - h, y.ct + h).unwrap() / (2.0 * h);
+                assert_relative_eq!(rate, 1.0 / u.gamma(), epsilon = 1e-6);
+                assert_eq!(wl.past_proper_time(1.0, x), wl.proper_time(1.0, y.ct));
+            }
+        }
+    }
+
+    #[test]
+    fn jerk() {
+        let c = 2.0;
+        let hyperbolic = HyperbolicWorldLine::new(
+            Vector4::new(1.0, 2.0, 3.0, 4.0),
+            Vector3::new(0.1, 0.2, 0.0),
+        )
+        .unwrap();
+        let circular = CircularWorldLine::new(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 1.0),
+            0.05,
+            c,
+        )
+        .unwrap();
+        let mut rng = Mcg128Xsl64::new(1);
+        for _ in 0..100 {
+            let x = Vector4::new(
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(-10.0..10.0),
+                rng.gen_range(0.0..20.0),
+            );
+            // hyperbolic motion turns u at the constant rate |a|, so da/ds = |a|² u
+            if let Ok((y, u, a)) = hyperbolic.past_intersection(c, x) {
+                let a_t = a.dot(u) / u.gamma();
+                let expected = u * (a.magnitude2() - a_t * a_t);
+                let jerk = hyperbolic.jerk(c, y, 1e-2).unwrap();
+                assert_relative_eq!(jerk, expected, max_relative = 1e-5);
+            }
+            // circular motion turns u and a at the rate |a|/|u| in the plane
+            let (y, u, a) = circular.past_intersection(c, x).unwrap();
+            let expected = u * -(a.magnitude2() / u.magnitude2());
+            let jerk = circular.jerk(c, y, 1e-2).unwrap();
+            assert_relative_eq!(jerk, expected, max_relative = 1e-5);
+        }
+        let inertial = InertialWorldLine::new(Vector4::zero(), Vector3::new(0.5, -1.0, 2.0));
+        let y = Vector4::from_ctv(3.0, Vector3::new(0.5, -1.0, 2.0) * (3.0 / 2.5f64.sqrt()));
+        assert_eq!(inertial.jerk(c, y, 1e-2).unwrap(), Vector3::zero());
+    }
+
+    #[test]
+    fn line_oscillate_proper_time() {
+        let center = Vector3::new(1.0, 2.0, 3.0);
+        let amp = Vector3::new(1.0, 0.5, 0.0);
+        for beta in [0.1, 0.9, 0.999] {
+            let omega = beta / amp.magnitude();
+            let wl = LineOscillateWorldLine::new(center, amp, omega / std::f64::consts::TAU, 1.0)
+                .unwrap();
+            // Simpson's rule on dτ/dx_0 = sqrt(1 - v²)
+            let rate = |ct: f64| (1.0 - (amp * (omega * (omega * ct).cos())).magnitude2()).sqrt();
+            let n = 20000;
+            for i in -7..=64 {
+                let ct = i as f64;
+                let h = ct / n as f64;
+                let simpson = (1..n)
+                    .map(|j| rate(j as f64 * h) * if j % 2 == 0 { 2.0 } else { 4.0 })
+                    .sum::<f64>()
+                    + rate(0.0)
+                    + rate(ct);
+                assert_relative_eq!(
+                    wl.proper_time(1.0, ct).unwrap(),
+                    simpson * h / 3.0,
+                    epsilon = 1e-9
+                );
+            }
+        }
+
+        // light speed at the center, τ = (1 - cos ω x_0) / ω in the first half period
+        let omega = 1.0 / amp.magnitude();
+        let wl =
+            LineOscillateWorldLine::new(center, amp, omega / std::f64::consts::TAU, 1.0).unwrap();
+        for ct in [0.5, 1.0, 2.0, 3.0] {
+            assert_relative_eq!(
+                wl.proper_time(1.0, ct).unwrap(),
+                (1.0 - (omega * ct).cos()) / omega,
+                epsilon = 1e-14
+            );
+        }
+    }
+
+    #[test]
+    fn discrete_world_line_proper_time() {
+        let mut wl = DiscreteWorldLine::new();
+        let u = Vector3::new(0.6, 0.0, 0.8);
+        for i in 0..10 {
+            let ct = i as f64 * 2.0;
+            wl.push(Vector4::from_ctv(ct, u * (ct / u.gamma())));
+        }
+        assert_eq!(
+            wl.proper_time(1.0, -0.1),
+            Err(WorldLineError::HistoryTooShort)
+        );
+        assert_eq!(wl.proper_time(1.0, 0.0), Ok(0.0));
+        assert_relative_eq!(wl.proper_time(1.0, 5.0).unwrap(), 5.0 / u.gamma());
+        assert_relative_eq!(wl.proper_time(1.0, 18.0).unwrap(), 18.0 / u.gamma());
+        assert_eq!(
+            wl.proper_time(1.0, 18.1),
+            Err(WorldLineError::HistoryTooShort)
+        );
+        let (y, _, _) = wl
+            .past_intersection(1.0, Vector4::new(0.0, 5.0, 0.0, 15.0))
+            .unwrap();
+        assert_relative_eq!(
+            wl.past_proper_time(1.0, Vector4::new(0.0, 5.0, 0.0, 15.0))
+                .unwrap(),
+            y.ct / u.gamma()
+        );
+    }
+
+    #[test]
+    fn circular_world_line_faster_than_light() {
+        let center = Vector3::zero();
+        let radius = Vector3::new(1.0, 0.0, 0.0);
+        let axis = Vector3::new(0.0, 1.0, 0.0);
+        assert!(CircularWorldLine::new(center, radius, axis, 0.2, 1.0).is_err());
+        assert!(CircularWorldLine::helical(center, radius, axis, 0.1, 0.8, 1.0).is_err());
+        assert!(CircularWorldLine::new(center, radius, Vector3::zero(), 0.1, 1.0).is_err());
+    }
+
+    #[test]
+    fn helical_world_line() {
+        let mut rng = Mcg128Xsl64::new(1);
+        for _ in 0..100000 {
+            let center = Vector3::new(
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+            );
+            let radius = Vector3::new(
+                rng.gen_range(-3.0..3.0),
+                rng.gen_range(-3.0..3.0),
+                rng.gen_range(-3.0..3.0),
+            );
+            let axis = Vector3::new(
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+                rng.gen_range(-1.0..1.0),
+            );
+            let beta: f64 = rng.gen_range(0.0..0.999);
+            let drift = beta * rng.gen_range(-1.0..1.0);
+            let r = (radius - axis.normalized() * radius.dot(axis.normalized())).magnitude();
+            let frequency = (beta * beta - drift * drift).sqrt() / r / std::f64::consts::TAU;
+            let wl =
+                CircularWorldLine::helical(center, radius, axis, frequency, drift, 1.0).unwrap();
+
+            let x = Vector4::new(
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-100.0..100.0),
+                rng.gen_range(-1000.0..1000.0),
+            );
+            let (px, pu, pa) = wl.past_intersection(1.0, x).unwrap();
+            let l = x - px;
+            assert!(l.ct >= 0.0);
+            assert!(
+                l.lorentz_norm2().abs() < 1e-8 * (1.0 + l.ct * l.ct),
+                "x={:?}\npx={:?}\nnorm={}\nbeta={}",
+                x,
+                px,
+                l.lorentz_norm2(),
+                beta,
+            );
+            let gamma = 1.0 / (1.0 - beta * beta).sqrt();
+            assert_relative_eq!(pu.magnitude(), gamma * beta, epsilon = 1e-9);
+            assert_relative_eq!(pu.dot(pa), 0.0, epsilon = 1e-9 * (1.0 + pa.magnitude()));
+
+            let (fx, fu, _) = wl.future_intersection(1.0, x).unwrap();
+            let l = fx - x;
+            assert!(l.ct >= 0.0);
+            assert!(l.lorentz_norm2().abs() < 1e-8 * (1.0 + l.ct * l.ct));
+            assert_relative_eq!(fu.magnitude(), gamma * beta, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn world_line_errors() {
+        let x = Vector3::zero();
+        assert_eq!(
+            LineOscillateWorldLine::new(x, Vector3::X_AXIS, 1.0, 1.0).err(),
+            Some(WorldLineError::FasterThanLight)
+        );
+        assert_eq!(
+            HyperbolicWorldLine::new(Vector4::zero(), x).err(),
+            Some(WorldLineError::InvalidParameter)
+        );
+        assert_eq!(
+            CircularWorldLine::new(x, Vector3::X_AXIS, x, 0.1, 1.0).err(),
+            Some(WorldLineError::InvalidParameter)
+        );
+        assert_eq!(
+            ParametricWorldLine::new(|_| Vector3::zero(), 1.0).err(),
+            Some(WorldLineError::FasterThanLight)
+        );
+        let mut wl = PiecewiseWorldLine::new(StaticWorldLine::new(x));
+        assert_eq!(
+            wl.push(f64::NEG_INFINITY, StaticWorldLine::new(x)),
+            Err(WorldLineError::InvalidParameter)
+        );
+
+        let mut wl = DiscreteWorldLine::new();
+        wl.push(Vector4::zero());
+        let observer = Vector4::new(0.0, 0.0, 1.0, 5.0);
+        assert_eq!(
+            wl.past_intersection(1.0, observer),
+            Err(WorldLineError::HistoryTooShort)
+        );
+        assert_eq!(
+            StaticWorldLine::new(x)
+                .future_intersection(1.0, observer)
+                .map(|p| p.0),
+            Ok(Vector4::new(0.0, 0.0, 0.0, 6.0))
+        );
+        assert_eq!(
+            BoostedWorldLine::new(wl, Vector3::X_AXIS).future_intersection(1.0, observer),
+            Err(WorldLineError::HistoryTooShort)
+        );
+        assert!(WorldLineError::SpaceLike.is_not_arrived());
+        assert!(!WorldLineError::NotConverged.is_not_arrived());
+    }
+
+    #[test]
+    fn past_intersections_warm_start() {
+        let center = Vector3::new(1.0, 2.0, 3.0);
+        let amp = Vector3::new(1.0, 0.5, 0.0);
+        let line_o = LineOscillateWorldLine::new(center, amp, 0.1, 1.0).unwrap();
+        let mut discrete = DiscreteWorldLine::with_interpolation(Interpolation::CubicHermite);
+        for i in -1000..=1000 {
+            let ct = i as f64 / 16.0;
+            discrete.push(
+                line_o
+                    .past_intersection(1.0, Vector4::from_ctv(ct, center))
+                    .unwrap()
+                    .0,
+            );
+        }
+        let world_lines: Vec<Box<dyn WorldLine>> = vec![
+            Box::new(line_o.clone()),
+            Box::new(BoostedWorldLine::new(
+                line_o.clone(),
+                Vector3::new(0.3, 0.0, 0.2),
+            )),
+            Box::new(CutOffWorldLine::new(line_o, 50.0)),
+            Box::new(CircularWorldLine::new(center, amp, Vector3::Z_AXIS, 0.1, 1.0).unwrap()),
+            Box::new(
+                ParametricWorldLine::new(move |ct| center + amp * (ct * 0.3).sin(), 0.4).unwrap(),
+            ),
+            Box::new(discrete),
+        ];
+        // a grid on the past light cone of the player, in the order of the app
+        let player = Vector4::new(0.0, 0.0, 0.0, 60.0);
+        let mut xs = Vec::new();
+        for x in -10..=10 {
+            for y in -10..=10 {
+                for z in -5..=5 {
+                    let p = Vector3::new(x as f64, y as f64, z as f64 * 0.5);
+                    xs.push(Vector4::from_ctv(player.ct - p.magnitude(), p));
+                }
+            }
+        }
+        for wl in world_lines.iter() {
+            let batch = wl.past_intersections(1.0, &xs);
+            assert_eq!(batch.len(), xs.len());
+            for (&x, batch) in xs.iter().zip(batch) {
+                let single = wl.past_intersection(1.0, x);
+                let (Ok(single), Ok(batch)) = (single, batch) else {
+                    assert_eq!(single.err(), batch.err());
+                    continue;
+                };
+                assert_relative_eq!(single.0, batch.0, epsilon = 1e-9);
+                assert_relative_eq!(single.1, batch.1, epsilon = 1e-8);
+                assert_relative_eq!(single.2, batch.2, epsilon = 1e-7);
+            }
         }
     }
 }
@@ -30,6 +30,13 @@ type Model = {
     electricOn: boolean;
     magneticOn: boolean;
     poyntingOn: boolean;
+    potentialOn: boolean;
+    energyOn: boolean;
+    stressOn: boolean;
+    classifyOn: boolean;
+    maxwellOn: boolean;
+    fluxOn: boolean;
+    clockOn: boolean;
     arrowLog: number;
     arrowFactor: number;
 };
@@ -38,6 +45,13 @@ const defaultModel: Model = {
     electricOn: true,
     magneticOn: true,
     poyntingOn: false,
+    potentialOn: false,
+    energyOn: false,
+    stressOn: false,
+    classifyOn: false,
+    maxwellOn: false,
+    fluxOn: false,
+    clockOn: false,
     arrowLog: 1,
     arrowFactor: 2,
 };
@@ -53,6 +67,25 @@ const initModel = {
         ...defaultModel,
         electricOn: false,
     },
+    ring: {
+        ...defaultModel,
+        electricOn: false,
+    },
+    segment: defaultModel,
+    kick: defaultModel,
+    atom: defaultModel,
+    synchrotron: defaultModel,
+    hertz: {
+        ...defaultModel,
+        magneticOn: false,
+    },
+    magnet: {
+        ...defaultModel,
+        electricOn: false,
+    },
+    cyclotron: defaultModel,
+    drift: defaultModel,
+    wave: defaultModel,
 } as const;
 
 
@@ -163,6 +196,11 @@ lorentz.onchange = (): void => {
     app.change_correct_lorentz(!lorentz.checked);
 }
 
+const fermiWalker = document.getElementById('fermi-walker') as HTMLInputElement;
+fermiWalker.onchange = (): void => {
+    app.change_fermi_walker(fermiWalker.checked);
+}
+
 const presetNodes = document.getElementsByName("preset") as NodeListOf<HTMLInputElement>;
 
 const presetChange = (): void => {
@@ -197,9 +235,66 @@ go1.onchange = gridOptionChange;
 const go2 = document.getElementById("grid-option2") as HTMLSelectElement;
 go2.onchange = gridOptionChange;
 
+const fieldModeNodes = document.getElementsByName("field-mode") as NodeListOf<HTMLInputElement>;
+const fieldModeChange = () => {
+    for (let i = 0; i < fieldModeNodes.length; i++) {
+        if (fieldModeNodes.item(i).checked) {
+            app.change_field_mode(fieldModeNodes.item(i).value);
+            fieldModeNodes.item(i)!.nextElementSibling!.classList.add("checked");
+        } else {
+            fieldModeNodes.item(i)!.nextElementSibling!.classList.remove("checked");
+        }
+    }
+};
+for (let i = 0; i < fieldModeNodes.length; i++) {
+    fieldModeNodes.item(i).onchange = fieldModeChange;
+}
+
+const integratorNodes = document.getElementsByName("integrator") as NodeListOf<HTMLInputElement>;
+const integratorChange = () => {
+    for (let i = 0; i < integratorNodes.length; i++) {
+        if (integratorNodes.item(i).checked) {
+            app.change_integrator(integratorNodes.item(i).value);
+            integratorNodes.item(i)!.nextElementSibling!.classList.add("checked");
+        } else {
+            integratorNodes.item(i)!.nextElementSibling!.classList.remove("checked");
+        }
+    }
+};
+for (let i = 0; i < integratorNodes.length; i++) {
+    integratorNodes.item(i).onchange = integratorChange;
+}
+
+const adaptiveStep = document.getElementById('adaptive-step') as HTMLInputElement;
+adaptiveStep.onchange = () => {
+    app.change_step_control(adaptiveStep.checked);
+};
+
+const fieldComponentNodes = document.getElementsByName("field-component") as NodeListOf<HTMLInputElement>;
+const fieldComponentChange = () => {
+    for (let i = 0; i < fieldComponentNodes.length; i++) {
+        if (fieldComponentNodes.item(i).checked) {
+            app.change_field_component(fieldComponentNodes.item(i).value);
+            fieldComponentNodes.item(i)!.nextElementSibling!.classList.add("checked");
+        } else {
+            fieldComponentNodes.item(i)!.nextElementSibling!.classList.remove("checked");
+        }
+    }
+};
+for (let i = 0; i < fieldComponentNodes.length; i++) {
+    fieldComponentNodes.item(i).onchange = fieldComponentChange;
+}
+
 const electricToggle = document.getElementById('electric-toggle') as HTMLInputElement;
 const magneticToggle = document.getElementById('magnetic-toggle') as HTMLInputElement;
 const poyntingToggle = document.getElementById('poynting-toggle') as HTMLInputElement;
+const potentialToggle = document.getElementById('potential-toggle') as HTMLInputElement;
+const energyToggle = document.getElementById('energy-toggle') as HTMLInputElement;
+const stressToggle = document.getElementById('stress-toggle') as HTMLInputElement;
+const classifyToggle = document.getElementById('classify-toggle') as HTMLInputElement;
+const maxwellToggle = document.getElementById('maxwell-toggle') as HTMLInputElement;
+const fluxToggle = document.getElementById('flux-toggle') as HTMLInputElement;
+const clockToggle = document.getElementById('clock-toggle') as HTMLInputElement;
 const setElectricToggle = (on: boolean): void => {
     electricToggle.checked = on;
     app.change_electric_on(on);
@@ -212,6 +307,34 @@ const setPoyntingToggle = (on: boolean): void => {
     poyntingToggle.checked = on;
     app.change_poynting_on(on);
 };
+const setPotentialToggle = (on: boolean): void => {
+    potentialToggle.checked = on;
+    app.change_potential_on(on);
+};
+const setEnergyToggle = (on: boolean): void => {
+    energyToggle.checked = on;
+    app.change_energy_on(on);
+};
+const setStressToggle = (on: boolean): void => {
+    stressToggle.checked = on;
+    app.change_stress_on(on);
+};
+const setClassifyToggle = (on: boolean): void => {
+    classifyToggle.checked = on;
+    app.change_classify_on(on);
+};
+const setMaxwellToggle = (on: boolean): void => {
+    maxwellToggle.checked = on;
+    app.change_maxwell_on(on);
+};
+const setFluxToggle = (on: boolean): void => {
+    fluxToggle.checked = on;
+    app.change_flux_on(on);
+};
+const setClockToggle = (on: boolean): void => {
+    clockToggle.checked = on;
+    app.change_clock_on(on);
+};
 electricToggle.onchange = () => {
     app.change_electric_on(electricToggle.checked);
 };
@@ -221,6 +344,27 @@ magneticToggle.onchange = () => {
 poyntingToggle.onchange = () => {
     app.change_poynting_on(poyntingToggle.checked);
 };
+potentialToggle.onchange = () => {
+    app.change_potential_on(potentialToggle.checked);
+};
+energyToggle.onchange = () => {
+    app.change_energy_on(energyToggle.checked);
+};
+stressToggle.onchange = () => {
+    app.change_stress_on(stressToggle.checked);
+};
+classifyToggle.onchange = () => {
+    app.change_classify_on(classifyToggle.checked);
+};
+maxwellToggle.onchange = () => {
+    app.change_maxwell_on(maxwellToggle.checked);
+};
+fluxToggle.onchange = () => {
+    app.change_flux_on(fluxToggle.checked);
+};
+clockToggle.onchange = () => {
+    app.change_clock_on(clockToggle.checked);
+};
 
 const arrowLog = document.getElementById("arrow-log") as HTMLInputElement;
 const arrowLogPlus = document.getElementById("arrow-log-plus") as HTMLButtonElement;
@@ -263,6 +407,13 @@ const resetModel = (preset: keyof typeof initModel) => {
     setElectricToggle(m.electricOn);
     setMagneticToggle(m.magneticOn);
     setPoyntingToggle(m.poyntingOn);
+    setPotentialToggle(m.potentialOn);
+    setEnergyToggle(m.energyOn);
+    setStressToggle(m.stressOn);
+    setClassifyToggle(m.classifyOn);
+    setMaxwellToggle(m.maxwellOn);
+    setFluxToggle(m.fluxOn);
+    setClockToggle(m.clockOn);
     app.reset_charge(preset);
 }
 
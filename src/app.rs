@@ -5,13 +5,17 @@ use web_sys::WebGl2RenderingContext;
 
 use backend::{Backend, LightingLocalData, LightingShader, Shader, Shape, VertexPositionNormal};
 use color::RGBA;
-use rmath::{vec3, Deg, Matrix, Quaternion, StaticWorldLine, Vector3, Vector4, WorldLine};
+use rmath::{
+    vec3, Deg, DipoleKind, FieldClass, Integrator, Matrix, ProperTime, Quaternion, SourceField,
+    StaticWorldLine, Vector3, Vector4, WorldLine, WorldLineError,
+};
 use shape::BuildData;
 
 use crate::{
     charge_set::{
-        ChargePreset, ChargeSet, CirclesChargeSet, EomChargeSet, EomWithStaticCharge,
-        LineOscillateCharge, StaticChargeSet,
+        check_maxwell, ChargeIntersection, ChargePreset, ChargeSet, CirclesChargeSet,
+        DipoleIntersection, DipoleSet, DistributionSet, EomChargeSet, EomWithStaticCharge,
+        LineOscillateCharge, StaticChargeSet, WorldLineChargeSet,
     },
     key::{GestureEvent, KeyManager, TouchManager},
     player::Player,
@@ -40,6 +44,11 @@ struct AppPhysics {
     charge_preset: ChargePreset,
     charges: Box<dyn ChargeSet>,
     player: Player,
+    integrator: Integrator,
+    /// Tolerance of adaptive steps, or None for fixed steps
+    step_tolerance: Option<f64>,
+    /// The player view follows Fermi–Walker transport
+    fermi_walker: bool,
 }
 
 pub struct InternalApp {
@@ -53,6 +62,17 @@ pub struct InternalApp {
     electric_on: bool,
     magnetic_on: bool,
     poynting_on: bool,
+    potential_on: bool,
+    energy_on: bool,
+    stress_on: bool,
+    classify_on: bool,
+    maxwell_on: bool,
+    flux_on: bool,
+    clock_on: bool,
+    surface_grid: bool,
+    field_mode: FieldMode,
+    field_component: FieldComponent,
+    field_status: FieldStatus,
 }
 
 impl AppRender {
@@ -91,9 +111,15 @@ impl AppInput {
 }
 
 impl AppPhysics {
-    fn new(c: f64, charge_preset: ChargePreset) -> AppPhysics {
+    fn new(
+        c: f64,
+        charge_preset: ChargePreset,
+        integrator: Integrator,
+        step_tolerance: Option<f64>,
+        fermi_walker: bool,
+    ) -> AppPhysics {
         let e = Vector3::new(0.5, 0.5, 0.0);
-        let (charges, player): (Box<dyn ChargeSet>, Player) = match charge_preset {
+        let (mut charges, mut player): (Box<dyn ChargeSet>, Player) = match charge_preset {
             ChargePreset::Static => (
                 Box::new(StaticChargeSet::new(e)),
                 Player::new(Vector3::new(0.0, 0.0, 30.0) + e),
@@ -114,18 +140,68 @@ impl AppPhysics {
                 Box::new(CirclesChargeSet::new(c)),
                 Player::new(Vector3::new(0.0, 0.0, 20.0) + e),
             ),
+            ChargePreset::Kick => (
+                Box::new(WorldLineChargeSet::new_kick(c, e)),
+                Player::new(Vector3::new(0.0, 0.0, 40.0) + e),
+            ),
+            ChargePreset::Atom => (
+                Box::new(EomWithStaticCharge::new_atom(c, e)),
+                Player::new(Vector3::new(0.0, 0.0, 20.0) + e),
+            ),
+            ChargePreset::Synchrotron => (
+                Box::new(EomChargeSet::new_synchrotron(c, e)),
+                Player::new(Vector3::new(0.0, 0.0, 30.0) + e),
+            ),
+            ChargePreset::Hertz => (
+                Box::new(DipoleSet::new_hertz(e)),
+                Player::new(Vector3::new(0.0, 0.0, 30.0) + e),
+            ),
+            ChargePreset::Magnet => (
+                Box::new(DipoleSet::new_magnet(e)),
+                Player::new(Vector3::new(0.0, 0.0, 20.0) + e),
+            ),
+            ChargePreset::Cyclotron => (
+                Box::new(EomChargeSet::new_cyclotron(c, e)),
+                Player::new(Vector3::new(0.0, 0.0, 20.0) + e),
+            ),
+            ChargePreset::Drift => (
+                Box::new(EomChargeSet::new_drift(c, e)),
+                Player::new(Vector3::new(0.0, 0.0, 30.0) + e),
+            ),
+            ChargePreset::Wave => (
+                Box::new(EomChargeSet::new_wave(c, e)),
+                Player::new(Vector3::new(0.0, 0.0, 30.0) + e),
+            ),
+            ChargePreset::Ring => (
+                Box::new(DistributionSet::new_ring(c)),
+                Player::new(Vector3::new(0.0, 0.0, 20.0) + e),
+            ),
+            ChargePreset::Segment => (
+                Box::new(DistributionSet::new_segment(e)),
+                Player::new(Vector3::new(0.0, 0.0, 30.0) + e),
+            ),
         };
+        charges.change_integrator(integrator);
+        charges.change_step_control(c, step_tolerance);
+        player.change_integrator(integrator);
+        player.change_fermi_walker(fermi_walker);
         AppPhysics {
             c,
             charge_preset,
             charges,
             player,
+            integrator,
+            step_tolerance,
+            fermi_walker,
         }
     }
 
-    fn tick(&mut self, dt: f64, key: &KeyManager, gesture: &[GestureEvent]) {
+    /// Charges are integrated up to the player's PLC, moved ahead by `ahead` in ct
+    /// so that future light cones of the measurement points meet them too
+    fn tick(&mut self, dt: f64, ahead: f64, key: &KeyManager, gesture: &[GestureEvent]) {
         self.player.tick(self.c, dt, key, gesture);
-        self.charges.tick(self.c, self.player.position());
+        let until = self.player.position() + Vector4::from_ctv(ahead, Vector3::zero());
+        self.charges.tick(self.c, until);
     }
 }
 
@@ -173,7 +249,13 @@ impl InternalApp {
         Ok(InternalApp {
             render,
             input: AppInput::new(width as f64, height as f64),
-            physics: AppPhysics::new(1.0, ChargePreset::Static),
+            physics: AppPhysics::new(
+                1.0,
+                ChargePreset::Static,
+                Integrator::default(),
+                None,
+                false,
+            ),
             measurement_points: grid_surface_measurement_points(),
             arrow_config: ArrowConfig::default(),
             correct_lorentz: false,
@@ -181,12 +263,29 @@ impl InternalApp {
             electric_on: true,
             magnetic_on: true,
             poynting_on: false,
+            potential_on: false,
+            energy_on: false,
+            stress_on: false,
+            classify_on: false,
+            maxwell_on: false,
+            flux_on: false,
+            clock_on: false,
+            surface_grid: true,
+            field_mode: FieldMode::Retarded,
+            field_component: FieldComponent::All,
+            field_status: FieldStatus::default(),
         })
     }
 
     #[inline(always)]
     pub fn restart_physics(&mut self) {
-        self.physics = AppPhysics::new(self.physics.c, self.physics.charge_preset);
+        self.physics = AppPhysics::new(
+            self.physics.c,
+            self.physics.charge_preset,
+            self.physics.integrator,
+            self.physics.step_tolerance,
+            self.physics.fermi_walker,
+        );
     }
 
     #[inline(always)]
@@ -200,7 +299,13 @@ impl InternalApp {
             }
             Ordering::Equal => false,
             Ordering::Greater => {
-                self.physics = AppPhysics::new(c, self.physics.charge_preset);
+                self.physics = AppPhysics::new(
+                    c,
+                    self.physics.charge_preset,
+                    self.physics.integrator,
+                    self.physics.step_tolerance,
+                    self.physics.fermi_walker,
+                );
                 true
             }
         }
@@ -208,7 +313,13 @@ impl InternalApp {
 
     #[inline(always)]
     pub fn reset_charge(&mut self, setup: &str) {
-        self.physics = AppPhysics::new(self.physics.c, setup.parse().unwrap());
+        self.physics = AppPhysics::new(
+            self.physics.c,
+            setup.parse().unwrap(),
+            self.physics.integrator,
+            self.physics.step_tolerance,
+            self.physics.fermi_walker,
+        );
     }
 
     #[inline(always)]
@@ -216,10 +327,12 @@ impl InternalApp {
         match setup {
             "2d" => {
                 self.charge_scale = 0.2;
+                self.surface_grid = true;
                 self.measurement_points = grid_surface_measurement_points();
             }
             "3d" => {
                 self.charge_scale = 0.3;
+                self.surface_grid = false;
                 self.measurement_points = grid_bulk_measurement_points();
             }
             _ => (),
@@ -246,6 +359,79 @@ impl InternalApp {
         self.poynting_on = poynting_on;
     }
 
+    #[inline(always)]
+    pub fn change_potential_on(&mut self, potential_on: bool) {
+        self.potential_on = potential_on;
+    }
+
+    #[inline(always)]
+    pub fn change_energy_on(&mut self, energy_on: bool) {
+        self.energy_on = energy_on;
+    }
+
+    #[inline(always)]
+    pub fn change_stress_on(&mut self, stress_on: bool) {
+        self.stress_on = stress_on;
+    }
+
+    #[inline(always)]
+    pub fn change_classify_on(&mut self, classify_on: bool) {
+        self.classify_on = classify_on;
+    }
+
+    #[inline(always)]
+    pub fn change_maxwell_on(&mut self, maxwell_on: bool) {
+        self.maxwell_on = maxwell_on;
+    }
+
+    #[inline(always)]
+    pub fn change_flux_on(&mut self, flux_on: bool) {
+        self.flux_on = flux_on;
+    }
+
+    #[inline(always)]
+    pub fn change_clock_on(&mut self, clock_on: bool) {
+        self.clock_on = clock_on;
+    }
+
+    #[inline(always)]
+    pub fn change_field_mode(&mut self, mode: &str) {
+        if let Ok(mode) = mode.parse() {
+            self.field_mode = mode;
+        }
+    }
+
+    #[inline(always)]
+    pub fn change_integrator(&mut self, integrator: &str) {
+        if let Ok(integrator) = integrator.parse() {
+            self.physics.integrator = integrator;
+            self.physics.charges.change_integrator(integrator);
+            self.physics.player.change_integrator(integrator);
+        }
+    }
+
+    #[inline(always)]
+    pub fn change_step_control(&mut self, adaptive: bool) {
+        let tolerance = adaptive.then_some(STEP_TOLERANCE);
+        self.physics.step_tolerance = tolerance;
+        self.physics
+            .charges
+            .change_step_control(self.physics.c, tolerance);
+    }
+
+    #[inline(always)]
+    pub fn change_fermi_walker(&mut self, fermi_walker: bool) {
+        self.physics.fermi_walker = fermi_walker;
+        self.physics.player.change_fermi_walker(fermi_walker);
+    }
+
+    #[inline(always)]
+    pub fn change_field_component(&mut self, component: &str) {
+        if let Ok(component) = component.parse() {
+            self.field_component = component;
+        }
+    }
+
     #[inline(always)]
     pub fn change_arrow_length_factor(&mut self, f: f64) {
         self.arrow_config.length_factor = f;
@@ -292,7 +478,16 @@ impl InternalApp {
         let dt = (timestamp - last_tick.unwrap_or(timestamp)) / 1000.0;
 
         let gesture = self.input.gesture(timestamp);
-        self.physics.tick(dt, &self.input.key_manager, &gesture);
+        // a measurement point within R of the player on its PLC sees a charge within R
+        // of the player on its future light cone no later than 2R after the player
+        let ahead = if self.field_mode.is_advanced() {
+            2.0 * self.measurement_radius()
+        } else {
+            0.0
+        };
+        self.physics
+            .tick(dt, ahead, &self.input.key_manager, &gesture);
+        self.compact_charges();
 
         self.render.backend.clear();
 
@@ -318,7 +513,10 @@ impl InternalApp {
             .shader
             .bind_shared_data(&self.render.backend, &self.render.charge_shape);
         let charge_scale = Matrix::uniform_scale(self.charge_scale);
-        for (q, (x, _, _)) in self.physics.charges.iter(c, player_position) {
+        for (q, intersection) in self.physics.charges.iter(c, player_position) {
+            let Ok((x, _, _)) = intersection else {
+                continue;
+            };
             let pos = lorentz_trans(x, player_position);
             let charge_data = LightingLocalData {
                 color: if q > 0.0 { RGBA::red() } else { RGBA::blue() },
@@ -333,46 +531,353 @@ impl InternalApp {
                 &charge_data,
             );
         }
+        // elements of distributions, smaller than point charges
+        let element_scale = Matrix::uniform_scale(self.charge_scale * 0.5);
+        for distribution in self.physics.charges.distributions() {
+            for (q, intersection) in distribution.past_elements(c, player_position, 64) {
+                let Ok((x, _, _)) = intersection else {
+                    continue;
+                };
+                let pos = lorentz_trans(x, player_position);
+                let element_data = LightingLocalData {
+                    color: if q > 0.0 { RGBA::red() } else { RGBA::blue() },
+                    model_view_projection: view_projection
+                        * Matrix::translation(pos.spatial())
+                        * element_scale,
+                    normal,
+                };
+                self.render.shader.draw(
+                    &self.render.backend,
+                    &self.render.charge_shape,
+                    &element_data,
+                );
+            }
+        }
+        for (kind, intersection) in self.physics.charges.dipoles(c, player_position) {
+            let Ok((x, _, _)) = intersection else {
+                continue;
+            };
+            let pos = lorentz_trans(x, player_position);
+            let dipole_data = LightingLocalData {
+                color: match kind {
+                    DipoleKind::Electric => RGBA::purple(),
+                    DipoleKind::Magnetic => RGBA::silver(),
+                },
+                model_view_projection: view_projection
+                    * Matrix::translation(pos.spatial())
+                    * charge_scale,
+                normal,
+            };
+            self.render.shader.draw(
+                &self.render.backend,
+                &self.render.charge_shape,
+                &dipole_data,
+            );
+        }
 
         self.render
             .shader
             .bind_shared_data(&self.render.backend, &self.render.arrow_shape);
-        for m in self.measurement_points.iter() {
-            let (pos_on_player_plc, _, _) = m.past_intersection(c, player_position).unwrap();
-
-            let charges = self.physics.charges.iter(c, pos_on_player_plc);
-            if charges.is_empty() {
-                continue;
-            }
-            let mut fs = Matrix::zero();
-            for (q, (x, u, a)) in charges {
-                let l = x - pos_on_player_plc;
-                fs = fs + Matrix::field_strength(q / self.physics.c, l.spatial(), u, a);
+        let positions = self
+            .measurement_points
+            .iter()
+            .map(|m| m.past_intersection(c, player_position).unwrap().0)
+            .collect::<Vec<_>>();
+        let mut field_status = FieldStatus {
+            advanced: self.field_mode.is_advanced(),
+            ..FieldStatus::default()
+        };
+        // spheres of (position, radius, color) drawn after the arrows
+        let mut markers = Vec::new();
+        for ((point, pos_on_player_plc), field) in self
+            .measurement_points
+            .iter()
+            .zip(&positions)
+            .zip(self.fields(&positions))
+        {
+            field_status.count(&field);
+            if self.clock_on && self.surface_grid {
+                // the grid clock as the player sees it, whether or not the field has arrived
+                if let Ok(tau) = point.proper_time(c, pos_on_player_plc.ct) {
+                    let pos = lorentz_trans(*pos_on_player_plc, player_position);
+                    markers.push((pos, 0.1, clock_color(tau / c)));
+                }
             }
-            fs = lorentz * fs * lorentz.transposed();
+            let Ok(field) = field.0 else {
+                continue;
+            };
+            let fs = match self.field_component {
+                FieldComponent::All => field.source.field_strength() + field.external,
+                FieldComponent::Near => field.source.near,
+                FieldComponent::Radiation => field.source.radiation,
+            };
+            let fs = lorentz * fs * lorentz.transposed();
+            let potential = lorentz * field.source.potential;
 
-            let pos = lorentz_trans(pos_on_player_plc, player_position);
+            let pos = lorentz_trans(*pos_on_player_plc, player_position);
             let projection = view_projection * Matrix::translation(pos.spatial());
+            let [ele_color, mag_color, poynting_color] = if self.classify_on {
+                // all arrows of the point take the color of its class
+                let color = match fs.classify_field_strength(c, NULL_FIELD_TOLERANCE) {
+                    FieldClass::ElectricLike => RGBA::green(),
+                    FieldClass::MagneticLike => RGBA::orange(),
+                    FieldClass::Null => RGBA::mediumvioletred(),
+                };
+                [color; 3]
+            } else {
+                match self.field_component {
+                    FieldComponent::All => [RGBA::green(), RGBA::orange(), RGBA::hotpink()],
+                    FieldComponent::Near => [RGBA::teal(), RGBA::tan(), RGBA::palevioletred()],
+                    FieldComponent::Radiation => [RGBA::crimson(), RGBA::coral(), RGBA::deeppink()],
+                }
+            };
             let ele = fs.field_strength_to_electric_field(self.physics.c);
             if self.electric_on && ele.magnitude2() > 1e-16 {
-                self.draw_arrow(ele, RGBA::green(), projection, normal);
+                self.draw_arrow(ele, ele_color, projection, normal);
             }
             let mag = fs.field_strength_to_magnetic_field();
             if self.magnetic_on && mag.magnitude2() > 1e-16 {
-                self.draw_arrow(mag, RGBA::orange(), projection, normal);
+                self.draw_arrow(mag, mag_color, projection, normal);
             }
+            let stress_energy = Matrix::stress_energy(fs, c);
             if self.poynting_on {
-                let poynting = ele.cross(mag) * c * c;
+                let poynting = stress_energy.stress_energy_to_energy_flux(c);
                 if poynting.magnitude2() > 1e-16 {
-                    self.draw_arrow(poynting, RGBA::hotpink(), projection, normal);
+                    self.draw_arrow(poynting, poynting_color, projection, normal);
+                }
+            }
+            if self.energy_on {
+                let energy = stress_energy.stress_energy_to_energy_density();
+                let radius = 0.3 * (1.0 - (-self.arrow_config.scalar_length(energy)).exp());
+                markers.push((pos, radius, RGBA::hotpink()));
+            }
+            if self.stress_on {
+                // momentum flux T^{ij} in the player frame as arrows both ways along
+                // its principal axes, pressure and tension in different colors
+                for (flux, axis) in stress_energy.stress_energy_to_principal_stresses() {
+                    if flux * flux > 1e-16 {
+                        let color = if flux > 0.0 {
+                            RGBA::dodgerblue()
+                        } else {
+                            RGBA::darkorange()
+                        };
+                        self.draw_arrow(axis * flux.abs(), color, projection, normal);
+                        self.draw_arrow(axis * -flux.abs(), color, projection, normal);
+                    }
+                }
+            }
+            if self.potential_on {
+                let vector = potential.four_potential_to_vector_potential();
+                if vector.magnitude2() > 1e-16 {
+                    self.draw_arrow(vector, RGBA::gold(), projection, normal);
+                }
+                if self.surface_grid {
+                    let phi = potential.four_potential_to_scalar_potential(c);
+                    markers.push((pos, 0.1, self.arrow_config.scalar_color(phi)));
                 }
             }
         }
+
+        self.render
+            .shader
+            .bind_shared_data(&self.render.backend, &self.render.charge_shape);
+        for (pos, radius, color) in markers {
+            let data = LightingLocalData {
+                color,
+                model_view_projection: view_projection
+                    * Matrix::translation(pos.spatial())
+                    * Matrix::uniform_scale(radius),
+                normal,
+            };
+            self.render
+                .shader
+                .draw(&self.render.backend, &self.render.charge_shape, &data);
+        }
         self.render.backend.flush();
+        self.field_status = field_status;
 
         Ok(())
     }
 
+    /// Radius R of the measurement points seen this frame, on the player's PLC
+    fn measurement_radius(&self) -> f64 {
+        let c = self.physics.c;
+        let player_position = self.physics.player.position();
+        self.measurement_points
+            .iter()
+            .filter_map(|m| m.past_intersection(c, player_position).ok())
+            .map(|(x, _, _)| player_position.ct - x.ct)
+            .fold(0.0, f64::max)
+    }
+
+    /// Every measurement point seen this frame is within radius R of the player
+    /// on the player's PLC, so it is in the causal future of the player's position 2R earlier.
+    fn compact_charges(&mut self) {
+        let player_position = self.physics.player.position();
+        let radius = self.measurement_radius();
+        let oldest =
+            Vector4::from_ctv(player_position.ct - 2.0 * radius, player_position.spatial());
+        self.physics.charges.compact(oldest);
+    }
+
+    /// Field strength and 4-potential at each of xs in the world frame.
+    ///
+    /// Sources without a field at a point are skipped, and it is an error only if
+    /// no source contributes. Failures other than not having arrived are returned
+    /// with the point, so that they are reported rather than hiding the other sources.
+    /// Neighboring points should be consecutive in xs for warm starts.
+    fn fields(&self, xs: &[Vector4]) -> Vec<SummedField> {
+        let c = self.physics.c;
+        let charge_fields = |x: Vector4,
+                             charges: Vec<ChargeIntersection>,
+                             f: fn(f64, Vector3, Vector3, Vector3) -> (Matrix, Matrix),
+                             g: fn(f64, Vector3, Vector3) -> Vector4| {
+            charges
+                .into_iter()
+                .map(|(q, intersection)| {
+                    let (y, u, a) = intersection?;
+                    let l = (y - x).spatial();
+                    let (near, radiation) = f(q / c, l, u, a);
+                    Ok(SourceField {
+                        near,
+                        radiation,
+                        potential: g(q / c, l, u),
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+        let dipole_fields =
+            |x: Vector4,
+             dipoles: Vec<DipoleIntersection>,
+             f: fn(
+                DipoleKind,
+                [Vector3; 3],
+                Vector3,
+                Vector3,
+                Vector3,
+                Vector3,
+            ) -> (Matrix, Matrix),
+             g: fn(DipoleKind, [Vector3; 3], Vector3, Vector3, Vector3) -> Vector4| {
+                dipoles
+                    .into_iter()
+                    .map(|(kind, intersection)| {
+                        let (y, [u, a, jerk], moment) = intersection?;
+                        let l = (y - x).spatial();
+                        let (near, radiation) = f(kind, moment, l, u, a, jerk);
+                        Ok(SourceField {
+                            near,
+                            radiation,
+                            potential: g(kind, moment, l, u, a),
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            };
+        // densities are charges, divided by c like q above
+        let distribution_fields = |x: Vector4, future: bool| {
+            self.physics
+                .charges
+                .distributions()
+                .iter()
+                .map(|d| {
+                    let field = if future {
+                        d.future_field(c, x)?
+                    } else {
+                        d.past_field(c, x)?
+                    };
+                    Ok(field * (1.0 / c))
+                })
+                .collect::<Vec<_>>()
+        };
+        let sum = |fields: Vec<Result<SourceField, WorldLineError>>| {
+            let mut fs = None;
+            let mut error = WorldLineError::SpaceLike;
+            let mut failed = Vec::new();
+            for field in fields {
+                match field {
+                    Ok(field) => fs = Some(fs.map_or(field, |fs| fs + field)),
+                    Err(e) if e.is_not_arrived() => error = e,
+                    Err(e) => failed.push(e),
+                }
+            }
+            (fs.ok_or(error), failed)
+        };
+        let retarded = || {
+            xs.iter()
+                .zip(self.physics.charges.iter_batch(c, xs))
+                .map(|(&x, charges)| {
+                    let mut fields = charge_fields(
+                        x,
+                        charges,
+                        Matrix::field_strength_parts,
+                        Vector4::four_potential,
+                    );
+                    fields.extend(dipole_fields(
+                        x,
+                        self.physics.charges.dipoles(c, x),
+                        Matrix::dipole_field_strength_parts,
+                        Vector4::dipole_four_potential,
+                    ));
+                    fields.extend(distribution_fields(x, false));
+                    sum(fields)
+                })
+                .collect::<Vec<_>>()
+        };
+        let advanced = || {
+            xs.iter()
+                .map(|&x| {
+                    let mut fields = charge_fields(
+                        x,
+                        self.physics.charges.iter_future(c, x),
+                        Matrix::advanced_field_strength_parts,
+                        Vector4::advanced_four_potential,
+                    );
+                    fields.extend(dipole_fields(
+                        x,
+                        self.physics.charges.dipoles_future(c, x),
+                        Matrix::advanced_dipole_field_strength_parts,
+                        Vector4::advanced_dipole_four_potential,
+                    ));
+                    fields.extend(distribution_fields(x, true));
+                    sum(fields)
+                })
+                .collect::<Vec<_>>()
+        };
+        let fields = match self.field_mode {
+            FieldMode::Retarded => retarded(),
+            FieldMode::Advanced => advanced(),
+            FieldMode::TimeSymmetric => retarded()
+                .into_iter()
+                .zip(advanced())
+                .map(|((r, mut failed), (a, advanced_failed))| {
+                    failed.extend(advanced_failed);
+                    let field = match (r, a) {
+                        (Ok(r), Ok(a)) => Ok((r + a) * 0.5),
+                        (Err(e), _) | (_, Err(e)) => Err(e),
+                    };
+                    (field, failed)
+                })
+                .collect(),
+        };
+        let external = self.physics.charges.external_field();
+        xs.iter()
+            .zip(fields)
+            .map(|(&x, (source, failed))| {
+                let field = match external {
+                    // the external field is there even before any source's field arrives
+                    Some(external) => Ok(PointField {
+                        source: source.unwrap_or_else(|_| SourceField::zero()),
+                        external: external.field_strength(c, x),
+                    }),
+                    None => source.map(|source| PointField {
+                        source,
+                        external: Matrix::zero(),
+                    }),
+                };
+                (field, failed)
+            })
+            .collect()
+    }
+
     pub fn info(&self) -> String {
         let mut s = String::new();
         s.push_str(&format!("player x = {}\n", self.physics.player.position()));
@@ -385,10 +890,49 @@ impl InternalApp {
             "player gamma = {:.3}\n",
             self.physics.player.velocity().gamma()
         ));
+        let (angle, axis) = self.physics.player.wigner_rotation().to_axis_angle();
+        s.push_str(&format!(
+            "player wigner rotation = {:.3} deg around {}\n",
+            Deg::from(angle).0,
+            axis
+        ));
         let c = self.physics.c;
+        // grid clocks are synchronized to the world frame and seen with light delay
+        let player_pos = self.physics.player.position();
+        if let Some(point) = self.measurement_points.iter().min_by(|a, b| {
+            let a = (a.pos - player_pos.spatial()).magnitude2();
+            let b = (b.pos - player_pos.spatial()).magnitude2();
+            a.total_cmp(&b)
+        }) {
+            if let Ok(tau) = point.past_proper_time(c, player_pos) {
+                s.push_str(&format!("grid {} tau = {:.3}\n", point.pos, tau / c));
+            }
+        }
         self.physics
             .charges
             .info(c, &mut s, self.physics.player.position());
+        self.physics
+            .charges
+            .radiation_info(c, &mut s, self.physics.player.position());
+        if self.flux_on {
+            self.physics
+                .charges
+                .flux_info(c, &mut s, self.physics.player.position());
+        }
+        self.field_status.info(&mut s);
+        if self.maxwell_on {
+            match check_maxwell(
+                self.physics.charges.as_ref(),
+                c,
+                self.physics.player.position(),
+                MAXWELL_STEP,
+            ) {
+                Ok(residual) => {
+                    s.push_str(&format!("maxwell residual = {:.1e}\n", residual.relative()))
+                }
+                Err(_) => s.push_str("maxwell residual = not all sources arrived\n"),
+            }
+        }
         s
     }
 
@@ -409,6 +953,145 @@ impl InternalApp {
     }
 }
 
+/// Fields whose invariants are below this fraction of the energy density are drawn as null,
+/// which is where the radiation zone begins
+const NULL_FIELD_TOLERANCE: f64 = 0.1;
+
+/// Seconds of a grid clock for its marker to go once around the hues
+const CLOCK_PERIOD: f64 = 10.0;
+
+/// Hue of the phase of a clock reading in seconds within CLOCK_PERIOD,
+/// red at multiples of the period
+fn clock_color(tau: f64) -> RGBA {
+    let h = (tau / CLOCK_PERIOD).rem_euclid(1.0) as f32 * 6.0;
+    let x = 1.0 - (h % 2.0 - 1.0).abs();
+    let (r, g, b) = match h as u32 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+    RGBA::new(r, g, b, 1.0)
+}
+
+/// Allowed error of u per step when the charges' steps are adaptive
+const STEP_TOLERANCE: f64 = 1e-6;
+
+/// Step of the differences checking Maxwell's equations at the player, small against the
+/// distances to the sources
+const MAXWELL_STEP: f64 = 1.0 / 64.0;
+
+/// Measurement points without field, or with failed sources, in the last frame
+#[derive(Default)]
+struct FieldStatus {
+    not_arrived: usize,
+    failed: usize,
+    last_error: Option<WorldLineError>,
+    /// The sources were also looked for on the future light cone
+    advanced: bool,
+}
+
+impl FieldStatus {
+    /// A point with some sources failed, or else with no field arrived
+    fn count(&mut self, (field, failed): &SummedField) {
+        match (failed.last(), field) {
+            (Some(&e), _) => {
+                self.failed += 1;
+                self.last_error = Some(e);
+            }
+            (None, Err(_)) => self.not_arrived += 1,
+            (None, Ok(_)) => (),
+        }
+    }
+
+    fn info(&self, s: &mut String) {
+        if self.not_arrived > 0 && self.advanced {
+            // charges beyond R of the player are not integrated ahead far enough
+            s.push_str(&format!(
+                "field not yet arrived or sources not integrated ahead at {} points\n",
+                self.not_arrived
+            ));
+        } else if self.not_arrived > 0 {
+            s.push_str(&format!(
+                "field not yet arrived at {} points\n",
+                self.not_arrived
+            ));
+        }
+        if let Some(e) = self.last_error {
+            s.push_str(&format!("source failed at {} points: {e}\n", self.failed));
+        }
+    }
+}
+
+/// Which solution of Maxwell's equations is rendered
+#[derive(Copy, Clone)]
+pub enum FieldMode {
+    /// Sources on the past light cone
+    Retarded,
+    /// Sources on the future light cone
+    Advanced,
+    /// Half retarded plus half advanced, as in Wheeler–Feynman absorber theory
+    TimeSymmetric,
+}
+
+impl FieldMode {
+    /// Whether sources on the future light cone are needed
+    fn is_advanced(self) -> bool {
+        !matches!(self, FieldMode::Retarded)
+    }
+}
+
+impl std::str::FromStr for FieldMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "retarded" => Ok(FieldMode::Retarded),
+            "advanced" => Ok(FieldMode::Advanced),
+            "time_symmetric" => Ok(FieldMode::TimeSymmetric),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Which part of the field strength is rendered
+#[derive(Copy, Clone)]
+pub enum FieldComponent {
+    All,
+    /// Velocity (Coulomb) field, falling off as 1/r²
+    Near,
+    /// Acceleration field, falling off as 1/r
+    Radiation,
+}
+
+impl std::str::FromStr for FieldComponent {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(FieldComponent::All),
+            "near" => Ok(FieldComponent::Near),
+            "radiation" => Ok(FieldComponent::Radiation),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Field summed over the sources that reached a point, or the not-arrived error if none did,
+/// and the errors of sources that failed for any other reason
+type SummedField = (Result<PointField, WorldLineError>, Vec<WorldLineError>);
+
+/// Field at a measurement point in the world frame
+#[derive(Copy, Clone)]
+struct PointField {
+    /// Field of the charges, dipoles and distributions of the set
+    source: SourceField,
+    /// Field strength of `ChargeSet::external_field`, which has no potential here
+    external: Matrix,
+}
+
 #[derive(Copy, Clone)]
 pub struct ArrowConfig {
     log_count: u8,
@@ -426,10 +1109,25 @@ impl Default for ArrowConfig {
 
 impl ArrowConfig {
     pub fn arrow_length(&self, v: Vector3) -> f64 {
-        let mut length = v.magnitude() * self.length_factor;
+        self.scalar_length(v.magnitude())
+    }
+
+    fn scalar_length(&self, s: f64) -> f64 {
+        let mut length = s * self.length_factor;
         for _ in 0..self.log_count {
             length = (1.0 + length).ln();
         }
         length
     }
+
+    /// White at zero, toward red for positive and blue for negative values,
+    /// scaled like the arrows
+    pub fn scalar_color(&self, s: f64) -> RGBA {
+        let t = 1.0 - (-self.scalar_length(s.abs())).exp() as f32;
+        if s > 0.0 {
+            RGBA::new(1.0, 1.0 - t, 1.0 - t, 1.0)
+        } else {
+            RGBA::new(1.0 - t, 1.0 - t, 1.0, 1.0)
+        }
+    }
 }
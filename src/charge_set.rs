@@ -1,10 +1,17 @@
 use rmath::{
-    vec3, vec4, DiscreteWorldLine, LineOscillateWorldLine, Matrix, PhaseSpace, StaticWorldLine,
-    Vector3, Vector4, WorldLine,
+    abraham_lorentz_dirac_force, dipole_power, fermi_walker_moment, lienard_power,
+    maxwell_residual, radiation_reaction_length, rotating_ring, static_ring, static_segment, vec3,
+    vec4, AdaptiveStepper, ChargeDistribution, Compaction, DipoleKind, DiscreteWorldLine,
+    ExternalField, HyperbolicWorldLine, InertialWorldLine, Integrator, LineOscillateWorldLine,
+    Matrix, MaxwellResidual, PhaseSpace, PiecewiseWorldLine, PlaneWave, ProperTime,
+    RadiationReaction, StaticWorldLine, UniformField, Vector3, Vector4, WorldLine, WorldLineError,
 };
 
 const Q: f64 = std::f64::consts::PI * 4.0;
 
+/// Step in cτ for the jerk of a dipole's world line, against the noise of its intersections
+const JERK_STEP: f64 = 1e-2;
+
 #[derive(Copy, Clone)]
 pub enum ChargePreset {
     Static,
@@ -12,6 +19,16 @@ pub enum ChargePreset {
     LineOscillate,
     EomWithStatic,
     Circle,
+    Kick,
+    Atom,
+    Synchrotron,
+    Hertz,
+    Magnet,
+    Cyclotron,
+    Drift,
+    Wave,
+    Ring,
+    Segment,
 }
 
 impl std::str::FromStr for ChargePreset {
@@ -24,19 +41,94 @@ impl std::str::FromStr for ChargePreset {
             "line_o" => Ok(ChargePreset::LineOscillate),
             "eom_with_static" => Ok(ChargePreset::EomWithStatic),
             "circle" => Ok(ChargePreset::Circle),
+            "kick" => Ok(ChargePreset::Kick),
+            "atom" => Ok(ChargePreset::Atom),
+            "synchrotron" => Ok(ChargePreset::Synchrotron),
+            "hertz" => Ok(ChargePreset::Hertz),
+            "magnet" => Ok(ChargePreset::Magnet),
+            "cyclotron" => Ok(ChargePreset::Cyclotron),
+            "drift" => Ok(ChargePreset::Drift),
+            "wave" => Ok(ChargePreset::Wave),
+            "ring" => Ok(ChargePreset::Ring),
+            "segment" => Ok(ChargePreset::Segment),
             _ => Err(()),
         }
     }
 }
 
+/// Charge and its intersection with a light cone, or why there is none
+pub type ChargeIntersection = (f64, Result<(Vector4, Vector3, Vector3), WorldLineError>);
+
+/// Dipole, with its position, velocity, acceleration and jerk, and moment on a light cone,
+/// or why there is none
+///
+/// The moment is normalized and differentiated as `Matrix::dipole_field_strength` takes it.
+pub type DipoleIntersection = (
+    DipoleKind,
+    Result<(Vector4, [Vector3; 3], [Vector3; 3]), WorldLineError>,
+);
+
 pub trait ChargeSet {
-    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<(f64, (Vector4, Vector3, Vector3))>;
+    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection>;
+
+    /// iter for each of xs, with warm starts between consecutive points
+    fn iter_batch(&self, c: f64, xs: &[Vector4]) -> Vec<Vec<ChargeIntersection>> {
+        xs.iter().map(|&x| self.iter(c, x)).collect()
+    }
+
+    /// Charges on the future light cone, for advanced fields
+    fn iter_future(&self, _c: f64, _player_pos: Vector4) -> Vec<ChargeIntersection> {
+        Vec::new()
+    }
+
+    /// Background field acting on the charges, which is also drawn
+    fn external_field(&self) -> Option<&dyn ExternalField> {
+        None
+    }
+
+    /// Continuous distributions, whose fields are integrated rather than summed over points
+    fn distributions(&self) -> &[Box<dyn ChargeDistribution>] {
+        &[]
+    }
+
+    /// Point dipoles on the past light cone
+    fn dipoles(&self, _c: f64, _player_pos: Vector4) -> Vec<DipoleIntersection> {
+        Vec::new()
+    }
+
+    /// Point dipoles on the future light cone, for advanced fields
+    fn dipoles_future(&self, _c: f64, _player_pos: Vector4) -> Vec<DipoleIntersection> {
+        Vec::new()
+    }
 
     fn tick(&mut self, _c: f64, _until: Vector4) {}
 
     fn change_c(&mut self, _current_c: f64, _new_c: f64) {}
 
+    /// Scheme for charges moving by their equation of motion
+    fn change_integrator(&mut self, _integrator: Integrator) {}
+
+    /// Adaptive steps within tolerance for charges moving by their equation of motion,
+    /// or the fixed step of the set if None
+    fn change_step_control(&mut self, _c: f64, _tolerance: Option<f64>) {}
+
+    /// Forget history no longer needed by observers in the causal future of oldest
+    fn compact(&mut self, _oldest: Vector4) {}
+
     fn info(&self, _c: f64, _s: &mut String, _player_pos: Vector4) {}
+
+    /// Power radiated by each charge when it emitted what the player sees now
+    fn radiation_info(&self, c: f64, s: &mut String, player_pos: Vector4) {
+        for (i, (q, intersection)) in self.iter(c, player_pos).into_iter().enumerate() {
+            if let Ok((_, u, a)) = intersection {
+                let power = lienard_power(q / c, u, a, c);
+                s.push_str(&format!("charge {i} power = {:.3e}\n", power));
+            }
+        }
+    }
+
+    /// Radiated energy flux through a sphere around the set, which is costly to integrate
+    fn flux_info(&self, _c: f64, _s: &mut String, _player_pos: Vector4) {}
 }
 
 pub struct StaticChargeSet {
@@ -52,10 +144,17 @@ impl StaticChargeSet {
 }
 
 impl ChargeSet for StaticChargeSet {
-    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<(f64, (Vector4, Vector3, Vector3))> {
+    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
         self.charges
             .iter()
-            .map(|(q, wl)| (*q, wl.past_intersection(c, player_pos).unwrap()))
+            .map(|(q, wl)| (*q, wl.past_intersection(c, player_pos)))
+            .collect()
+    }
+
+    fn iter_future(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
+        self.charges
+            .iter()
+            .map(|(q, wl)| (*q, wl.future_intersection(c, player_pos)))
             .collect()
     }
 }
@@ -65,16 +164,32 @@ pub struct EomCharge {
     q: f64,
     phase_space: PhaseSpace,
     world_line: DiscreteWorldLine,
+    /// Clock reading of `world_line` at the start, where the charge's clock is set to zero
+    clock_start: f64,
+    reaction: RadiationReaction,
+    /// Lorentz four-force over mass of the previous step and its ds,
+    /// for the reduced-order ALD jerk
+    last_force: Option<(Vector4, f64)>,
+    /// Energy over mc² taken by the reaction force since the start,
+    /// to be balanced by the energy radiated by Liénard's formula
+    reaction_work: f64,
+    radiated: f64,
+    integrator: Integrator,
+    /// Step-size control, or None for the fixed ds of the set
+    stepper: Option<AdaptiveStepper>,
 }
 
 pub struct EomChargeSet {
     charges: Vec<EomCharge>,
+    external: Option<Box<dyn ExternalField>>,
 }
 
 impl EomCharge {
     pub fn new(m: f64, q: f64, x: Vector4, u: Vector3) -> EomCharge {
+        // at rest before the start, so its clock reads the elapsed x_0 there
+        let rest = 1e4;
         let mut wl = DiscreteWorldLine::new();
-        wl.push(Vector4::from_ctv(x.ct - 1e4, x.spatial()));
+        wl.push(Vector4::from_ctv(x.ct - rest, x.spatial()));
         wl.push(Vector4::from_ctv(x.ct - 1e3, x.spatial()));
         wl.push(Vector4::from_ctv(x.ct - 1e2, x.spatial()));
         wl.push(Vector4::from_ctv(x.ct - 1e1, x.spatial()));
@@ -85,19 +200,123 @@ impl EomCharge {
             q,
             phase_space: PhaseSpace::new(u, x),
             world_line: wl,
+            clock_start: rest,
+            reaction: RadiationReaction::None,
+            last_force: None,
+            reaction_work: 0.0,
+            radiated: 0.0,
+            integrator: Integrator::default(),
+            stepper: None,
         }
     }
 
-    fn tick(&mut self, fs: Matrix, ds: f64) {
-        let force = fs
-            * (Matrix::eta() * Vector4::from_velocity(self.phase_space.velocity))
-            * (self.q / self.m);
-        self.phase_space.tick_in_world_frame(ds, force.spatial());
+    pub fn with_radiation_reaction(mut self, reaction: RadiationReaction) -> EomCharge {
+        self.reaction = reaction;
+        self
+    }
+
+    /// Step in the field of everything else, given at any event,
+    /// by ds or by what the adaptive stepper chooses
+    fn tick(&mut self, c: f64, ds: f64, field: impl Fn(Vector4) -> Matrix) {
+        let ds = self.stepper.as_ref().map_or(ds, AdaptiveStepper::step_size);
+        let u = Vector4::from_velocity(self.phase_space.velocity);
+        let s0 = radiation_reaction_length(self.q / c, self.q / self.m);
+        let (force, reaction) = if self.reaction == RadiationReaction::None {
+            (Vector4::zero(), Vector4::zero())
+        } else {
+            let (fs, fs_rate) = self.field_at(ds, &field);
+            let force = fs.lorentz_four_force(self.q, self.m, u);
+            let reaction = match (self.reaction, self.last_force) {
+                (RadiationReaction::LandauLifshitz, _) => {
+                    fs.landau_lifshitz_force(fs_rate, self.q, self.m, u, s0)
+                }
+                (RadiationReaction::ReducedAbrahamLorentzDirac, Some((last, last_ds))) => {
+                    abraham_lorentz_dirac_force((force - last) / last_ds, u, s0)
+                }
+                _ => Vector4::zero(),
+            };
+            (force, reaction)
+        };
+        let (integrator, q_over_m) = (self.integrator, self.q / self.m);
+        let advance = |p: &mut PhaseSpace, ds: f64| {
+            p.step_in_field(integrator, ds, &field, q_over_m, reaction.spatial());
+        };
+        let ds = match &mut self.stepper {
+            Some(stepper) => stepper.step(integrator.order(), &mut self.phase_space, advance),
+            None => {
+                advance(&mut self.phase_space, ds);
+                ds
+            }
+        };
+        if self.reaction != RadiationReaction::None {
+            self.last_force = Some((force, ds));
+            self.reaction_work -= reaction.ct * ds;
+            self.radiated += u.ct * s0 * force.lorentz_norm2() * ds;
+        }
         self.world_line.push(self.phase_space.position);
     }
+
+    /// Start adaptive steps from ds within tolerance, or go back to fixed steps if None
+    fn change_step_control(&mut self, ds: f64, tolerance: Option<f64>) {
+        self.stepper = tolerance.map(|tolerance| AdaptiveStepper::new(ds, tolerance));
+    }
+
+    fn change_c(&mut self, current_c: f64, new_c: f64) {
+        self.phase_space.change_c(current_c, new_c);
+        if let Some(stepper) = &mut self.stepper {
+            stepper.change_c(current_c, new_c);
+        }
+    }
+
+    /// Steps taken by the adaptive stepper and the range of the latest ds, in s = cτ
+    fn step_info(&self, i: usize, s: &mut String) {
+        let Some(stepper) = &self.stepper else {
+            return;
+        };
+        let (min, max) = stepper
+            .history()
+            .fold((f64::INFINITY, 0.0_f64), |(min, max), (_, ds)| {
+                (min.min(ds), max.max(ds))
+            });
+        s.push_str(&format!(
+            "charge {i} steps = {} ({} rejected), ds = {:.2e} .. {:.2e}\n",
+            stepper.accepted(),
+            stepper.rejected(),
+            min,
+            max
+        ));
+    }
+
+    /// Energy budget of the radiation reaction since the start, over mc²
+    fn energy_info(&self, i: usize, s: &mut String) {
+        if self.reaction == RadiationReaction::None {
+            return;
+        }
+        s.push_str(&format!(
+            "charge {i} reaction work = {:.3e}, radiated = {:.3e}\n",
+            self.reaction_work, self.radiated
+        ));
+    }
+
+    /// The field at the charge and its derivative along the 4-velocity if needed
+    fn field_at(&self, ds: f64, field: impl Fn(Vector4) -> Matrix) -> (Matrix, Matrix) {
+        let x = self.phase_space.position;
+        let fs = field(x);
+        if self.reaction != RadiationReaction::LandauLifshitz {
+            return (fs, Matrix::zero());
+        }
+        let dx = Vector4::from_velocity(self.phase_space.velocity) * ds;
+        let rate = (field(x + dx) + field(x - dx) * -1.0) * (0.5 / ds);
+        (fs, rate)
+    }
 }
 
 impl EomChargeSet {
+    /// Fixed ds, and where adaptive steps start
+    fn step(c: f64) -> f64 {
+        1.0 / 100.0 * c
+    }
+
     pub fn new_fixed_two_charges(c: f64, t: f64) -> EomChargeSet {
         let v = 5.0;
         let u = v / c;
@@ -106,49 +325,161 @@ impl EomChargeSet {
         let c2 = EomCharge::new(1.0, Q, vec4(-u, -r, 0.0, t), vec3(u, 0.0, 0.0));
         EomChargeSet {
             charges: vec![c1, c2],
+            external: None,
+        }
+    }
+
+    /// A charge gyrating in a uniform magnetic field with a proper period 2πm / (QB) = 40 in cτ,
+    /// so with a period of 40γ / c, about 11.2 seconds at c = 4 where u = 0.5
+    pub fn new_cyclotron(c: f64, x: Vector3) -> EomChargeSet {
+        let u = 2.0 / c;
+        let b = 0.0125;
+        let charge = EomCharge::new(1.0, Q, Vector4::from_ctv(0.0, x), vec3(u, 0.0, 0.0));
+        EomChargeSet {
+            charges: vec![charge],
+            external: Some(Box::new(UniformField::new(
+                Vector3::zero(),
+                Vector3::Z_AXIS * b,
+            ))),
+        }
+    }
+
+    /// A heavy relativistic charge in a uniform magnetic field, spiraling in as it radiates
+    pub fn new_synchrotron(_c: f64, x: Vector3) -> EomChargeSet {
+        let (m, u, r) = (20.0, 2.0, 5.0);
+        let b = u / (Q / m * r);
+        // the field is uniform, so the jerk of the Lorentz force needs no field derivative
+        let charge = EomCharge::new(m, Q, Vector4::from_ctv(0.0, x), vec3(u, 0.0, 0.0))
+            .with_radiation_reaction(RadiationReaction::ReducedAbrahamLorentzDirac);
+        EomChargeSet {
+            charges: vec![charge],
+            external: Some(Box::new(UniformField::new(
+                Vector3::zero(),
+                Vector3::Z_AXIS * b,
+            ))),
+        }
+    }
+
+    /// A charge starting at rest in crossed fields, drifting along E × B at E / B = c / 4
+    pub fn new_drift(c: f64, x: Vector3) -> EomChargeSet {
+        let b = 0.0125;
+        // E < cB for any c, so the field is magnetic-like and has a drift frame
+        let charge = EomCharge::new(1.0, Q, Vector4::from_ctv(0.0, x), Vector3::zero());
+        EomChargeSet {
+            charges: vec![charge],
+            external: Some(Box::new(UniformField::new(
+                Vector3::Y_AXIS * (0.25 * c * b),
+                Vector3::Z_AXIS * b,
+            ))),
+        }
+    }
+
+    /// A charge starting at rest, driven by a plane wave along z polarized along x
+    pub fn new_wave(_c: f64, x: Vector3) -> EomChargeSet {
+        let wave_length = 16.0;
+        let charge = EomCharge::new(1.0, Q, Vector4::from_ctv(0.0, x), Vector3::zero());
+        EomChargeSet {
+            charges: vec![charge],
+            external: Some(Box::new(PlaneWave::new(
+                Vector3::X_AXIS * 0.04,
+                Vector3::Z_AXIS,
+                std::f64::consts::TAU / wave_length,
+                0.0,
+            ))),
         }
     }
 }
 
 impl ChargeSet for EomChargeSet {
-    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<(f64, (Vector4, Vector3, Vector3))> {
+    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
+        self.charges
+            .iter()
+            .map(|charge| (charge.q, charge.world_line.past_intersection(c, player_pos)))
+            .collect()
+    }
+
+    fn iter_batch(&self, c: f64, xs: &[Vector4]) -> Vec<Vec<ChargeIntersection>> {
+        batch_intersections(
+            self.charges
+                .iter()
+                .map(|charge| (charge.q, &charge.world_line as &dyn WorldLine)),
+            c,
+            xs,
+        )
+    }
+
+    fn iter_future(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
         self.charges
             .iter()
-            .filter_map(move |charge| {
-                charge
-                    .world_line
-                    .past_intersection(c, player_pos)
-                    .map(|x| (charge.q, x))
+            .map(|charge| {
+                (
+                    charge.q,
+                    charge.world_line.future_intersection(c, player_pos),
+                )
             })
             .collect()
     }
 
+    fn external_field(&self) -> Option<&dyn ExternalField> {
+        self.external.as_deref()
+    }
+
     fn tick(&mut self, c: f64, until: Vector4) {
-        let ds = 1.0 / 100.0 * c;
+        let ds = Self::step(c);
         while !self.charges.iter().all(|charge| {
             charge.phase_space.position.ct >= until.ct
                 || (charge.phase_space.position - until).lorentz_norm2() >= 0.0
         }) {
+            // the charge steps in the field of the others
             let i = most_past_charge_index(&self.charges);
-            let position = self.charges[i].phase_space.position;
-            let fs = field_strength_from_charges(c, &self.charges, i, position);
-            self.charges[i].tick(fs, ds);
+            let mut charge = self.charges.remove(i);
+            charge.tick(c, ds, |position| {
+                let fs = field_strength_from_charges(c, &self.charges, position);
+                match &self.external {
+                    Some(external) => fs + external.field_strength(c, position),
+                    None => fs,
+                }
+            });
+            self.charges.insert(i, charge);
         }
     }
 
     fn change_c(&mut self, current_c: f64, new_c: f64) {
         for charge in self.charges.iter_mut() {
-            charge.phase_space.change_c(current_c, new_c);
+            charge.change_c(current_c, new_c);
         }
     }
 
+    fn change_integrator(&mut self, integrator: Integrator) {
+        for charge in self.charges.iter_mut() {
+            charge.integrator = integrator;
+        }
+    }
+
+    fn change_step_control(&mut self, c: f64, tolerance: Option<f64>) {
+        for charge in self.charges.iter_mut() {
+            charge.change_step_control(Self::step(c), tolerance);
+        }
+    }
+
+    fn compact(&mut self, oldest: Vector4) {
+        compact_charges(&mut self.charges, oldest);
+    }
+
     fn info(&self, c: f64, s: &mut String, player_pos: Vector4) {
         for (i, charge) in self.charges.iter().enumerate() {
-            let Some((x, u, _)) = charge.world_line.past_intersection(c, player_pos) else {
+            let Ok((x, u, _)) = charge.world_line.past_intersection(c, player_pos) else {
                 continue;
             };
             s.push_str(&format!("charge {i} x = {}\n", x));
             s.push_str(&format!("charge {i} gamma = {:.3}\n", u.gamma()));
+            // the absolute reading survives compaction, unlike samples near the start
+            if let Ok(tau) = charge.world_line.past_proper_time(c, player_pos) {
+                let tau = tau - charge.clock_start;
+                s.push_str(&format!("charge {i} tau = {:.3}\n", tau / c));
+            }
+            charge.energy_info(i, s);
+            charge.step_info(i, s);
         }
     }
 }
@@ -171,11 +502,99 @@ impl LineOscillateCharge {
 }
 
 impl ChargeSet for LineOscillateCharge {
-    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<(f64, (Vector4, Vector3, Vector3))> {
-        self.world_line
-            .past_intersection(c, player_pos)
-            .into_iter()
-            .map(|x| (self.q, x))
+    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
+        vec![(self.q, self.world_line.past_intersection(c, player_pos))]
+    }
+
+    fn iter_batch(&self, c: f64, xs: &[Vector4]) -> Vec<Vec<ChargeIntersection>> {
+        batch_intersections([(self.q, &self.world_line as &dyn WorldLine)], c, xs)
+    }
+
+    fn iter_future(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
+        vec![(self.q, self.world_line.future_intersection(c, player_pos))]
+    }
+
+    fn info(&self, c: f64, s: &mut String, player_pos: Vector4) {
+        if let Ok(tau) = self.world_line.past_proper_time(c, player_pos) {
+            s.push_str(&format!("charge tau = {:.3}\n", tau / c));
+        }
+    }
+
+    fn radiation_info(&self, c: f64, s: &mut String, player_pos: Vector4) {
+        if let Ok((_, u, a)) = self.world_line.past_intersection(c, player_pos) {
+            let power = lienard_power(self.q / c, u, a, c);
+            s.push_str(&format!("charge power = {:.3e}\n", power));
+        }
+    }
+
+    fn flux_info(&self, c: f64, s: &mut String, player_pos: Vector4) {
+        let (center, radius) = (vec3(1.2, 0.5, 0.0), 30.0);
+        if let Ok(flux) = radiated_flux(self, c, center, radius, player_pos.ct) {
+            s.push_str(&format!(
+                "flux through r = {radius} sphere = {:.3e}\n",
+                flux
+            ));
+        }
+    }
+}
+
+/// Charges moving on prescribed world lines
+pub struct WorldLineChargeSet {
+    charges: Vec<(f64, Box<dyn WorldLine>)>,
+}
+
+impl WorldLineChargeSet {
+    /// A charge at rest, kicked to u = 2, coasting and stopped again,
+    /// each kick being uniformly accelerated for 1 second
+    pub fn new_kick(c: f64, x0: Vector3) -> WorldLineChargeSet {
+        let dt = c; // 1 second
+        let u = 2.0;
+        let alpha = u / dt;
+        let kick_length = ((1.0 + u * u).sqrt() - 1.0) / alpha;
+        let coast_length = u / (1.0 + u * u).sqrt() * 2.0 * dt;
+        let x = Vector3::X_AXIS;
+
+        let start = x0 - x * (kick_length + 0.5 * coast_length);
+        let mut wl = PiecewiseWorldLine::new(StaticWorldLine::new(start));
+        let t = 5.0 * dt;
+        wl.push(
+            t,
+            HyperbolicWorldLine::new(Vector4::from_ctv(t, start), x * alpha).unwrap(),
+        )
+        .unwrap();
+        let t = t + dt;
+        let coast = start + x * kick_length;
+        wl.push(
+            t,
+            InertialWorldLine::new(Vector4::from_ctv(t, coast), x * u),
+        )
+        .unwrap();
+        let t = t + 2.0 * dt;
+        let stop = coast + x * (coast_length + kick_length);
+        wl.push(
+            t,
+            HyperbolicWorldLine::new(Vector4::from_ctv(t + dt, stop), x * -alpha).unwrap(),
+        )
+        .unwrap();
+        wl.push(t + dt, StaticWorldLine::new(stop)).unwrap();
+        WorldLineChargeSet {
+            charges: vec![(Q, Box::new(wl))],
+        }
+    }
+}
+
+impl ChargeSet for WorldLineChargeSet {
+    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
+        self.charges
+            .iter()
+            .map(|(q, wl)| (*q, wl.past_intersection(c, player_pos)))
+            .collect()
+    }
+
+    fn iter_future(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
+        self.charges
+            .iter()
+            .map(|(q, wl)| (*q, wl.future_intersection(c, player_pos)))
             .collect()
     }
 }
@@ -184,9 +603,15 @@ pub struct EomWithStaticCharge {
     q: f64,
     world_line: StaticWorldLine,
     charges: Vec<EomCharge>,
+    external: Option<Box<dyn ExternalField>>,
 }
 
 impl EomWithStaticCharge {
+    /// Fixed ds, and where adaptive steps start
+    fn step(c: f64) -> f64 {
+        1.0 / 128.0 * c
+    }
+
     pub fn new(c: f64, t: f64, x0: Vector3) -> EomWithStaticCharge {
         let r = 2.0;
         let v = 8.0;
@@ -201,47 +626,122 @@ impl EomWithStaticCharge {
             q: -Q,
             world_line: StaticWorldLine::new(x0),
             charges: vec![c1],
+            external: None,
+        }
+    }
+
+    /// A classical atom, a heavy charge orbiting a static opposite charge at radius 3,
+    /// collapsing as it radiates
+    pub fn new_atom(c: f64, x0: Vector3) -> EomWithStaticCharge {
+        let (m, r) = (10.0, 3.0);
+        // circular orbit of u² / r = (q / m) E / c in the slow limit
+        let u = (Q / m * Q / (4.0 * std::f64::consts::PI * c * r)).sqrt();
+        let c1 = EomCharge::new(
+            m,
+            Q,
+            Vector4::from_ctv(0.0, vec3(0.0, r, 0.0) + x0),
+            vec3(u, 0.0, 0.0),
+        )
+        .with_radiation_reaction(RadiationReaction::LandauLifshitz);
+        EomWithStaticCharge {
+            q: -Q,
+            world_line: StaticWorldLine::new(x0),
+            charges: vec![c1],
+            external: None,
         }
     }
 }
 
 impl ChargeSet for EomWithStaticCharge {
-    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<(f64, (Vector4, Vector3, Vector3))> {
-        let mut v = self
-            .world_line
-            .past_intersection(c, player_pos)
-            .into_iter()
-            .map(|x| (self.q, x))
-            .collect::<Vec<_>>();
-        v.extend(self.charges.iter().filter_map(move |charge| {
-            charge
-                .world_line
-                .past_intersection(c, player_pos)
-                .map(|x| (charge.q, x))
+    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
+        let mut v = vec![(self.q, self.world_line.past_intersection(c, player_pos))];
+        v.extend(
+            self.charges
+                .iter()
+                .map(|charge| (charge.q, charge.world_line.past_intersection(c, player_pos))),
+        );
+        v
+    }
+
+    fn iter_batch(&self, c: f64, xs: &[Vector4]) -> Vec<Vec<ChargeIntersection>> {
+        batch_intersections(
+            std::iter::once((self.q, &self.world_line as &dyn WorldLine)).chain(
+                self.charges
+                    .iter()
+                    .map(|charge| (charge.q, &charge.world_line as &dyn WorldLine)),
+            ),
+            c,
+            xs,
+        )
+    }
+
+    fn iter_future(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
+        let mut v = vec![(self.q, self.world_line.future_intersection(c, player_pos))];
+        v.extend(self.charges.iter().map(|charge| {
+            (
+                charge.q,
+                charge.world_line.future_intersection(c, player_pos),
+            )
         }));
         v
     }
 
+    fn external_field(&self) -> Option<&dyn ExternalField> {
+        self.external.as_deref()
+    }
+
     fn tick(&mut self, c: f64, until: Vector4) {
-        let ds = 1.0 / 128.0 * c;
+        let ds = Self::step(c);
         while !self.charges.iter().all(|c| {
             c.phase_space.position.ct >= until.ct
                 || (c.phase_space.position - until).lorentz_norm2() >= 0.0
         }) {
             let i = most_past_charge_index(&self.charges);
-            let position = self.charges[i].phase_space.position;
-            let mut fs = field_strength_from_charges(c, &self.charges, i, position);
-            if let Some((x, u, a)) = self.world_line.past_intersection(c, position) {
-                fs =
-                    fs + Matrix::field_strength(self.q / c, x.spatial() - position.spatial(), u, a);
-            }
-            self.charges[i].tick(fs, ds);
+            let mut charge = self.charges.remove(i);
+            charge.tick(c, ds, |position| {
+                let fs = field_strength_from_charges(c, &self.charges, position);
+                let fs = match self.world_line.past_intersection(c, position) {
+                    Ok((x, u, a)) => {
+                        let l = x.spatial() - position.spatial();
+                        fs + Matrix::field_strength(self.q / c, l, u, a)
+                    }
+                    Err(_) => fs,
+                };
+                match &self.external {
+                    Some(external) => fs + external.field_strength(c, position),
+                    None => fs,
+                }
+            });
+            self.charges.insert(i, charge);
         }
     }
 
     fn change_c(&mut self, current_c: f64, new_c: f64) {
         for charge in self.charges.iter_mut() {
-            charge.phase_space.change_c(current_c, new_c);
+            charge.change_c(current_c, new_c);
+        }
+    }
+
+    fn change_integrator(&mut self, integrator: Integrator) {
+        for charge in self.charges.iter_mut() {
+            charge.integrator = integrator;
+        }
+    }
+
+    fn change_step_control(&mut self, c: f64, tolerance: Option<f64>) {
+        for charge in self.charges.iter_mut() {
+            charge.change_step_control(Self::step(c), tolerance);
+        }
+    }
+
+    fn compact(&mut self, oldest: Vector4) {
+        compact_charges(&mut self.charges, oldest);
+    }
+
+    fn info(&self, _c: f64, s: &mut String, _player_pos: Vector4) {
+        for (i, charge) in self.charges.iter().enumerate() {
+            charge.energy_info(i, s);
+            charge.step_info(i, s);
         }
     }
 }
@@ -272,13 +772,60 @@ impl CirclesChargeSet {
 }
 
 impl ChargeSet for CirclesChargeSet {
-    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<(f64, (Vector4, Vector3, Vector3))> {
+    fn iter(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
         self.world_line
             .iter()
-            .filter_map(|wl| wl.past_intersection(c, player_pos).map(|x| (self.q, x)))
+            .map(|wl| (self.q, wl.past_intersection(c, player_pos)))
             .collect::<Vec<_>>()
     }
 
+    fn iter_batch(&self, c: f64, xs: &[Vector4]) -> Vec<Vec<ChargeIntersection>> {
+        batch_intersections(
+            self.world_line
+                .iter()
+                .map(|wl| (self.q, wl as &dyn WorldLine)),
+            c,
+            xs,
+        )
+    }
+
+    fn iter_future(&self, c: f64, player_pos: Vector4) -> Vec<ChargeIntersection> {
+        self.world_line
+            .iter()
+            .map(|wl| (self.q, wl.future_intersection(c, player_pos)))
+            .collect::<Vec<_>>()
+    }
+
+    /// The charges are identical, so only their sum is shown. It adds the powers
+    /// as if the charges radiated independently, which the ring's coherent field does not.
+    fn radiation_info(&self, c: f64, s: &mut String, player_pos: Vector4) {
+        let power = self
+            .iter(c, player_pos)
+            .into_iter()
+            .filter_map(|(q, intersection)| {
+                let (_, u, a) = intersection.ok()?;
+                Some(lienard_power(q / c, u, a, c))
+            })
+            .sum::<f64>();
+        s.push_str(&format!("incoherent sum of powers = {:.3e}\n", power));
+    }
+
+    fn flux_info(&self, c: f64, s: &mut String, player_pos: Vector4) {
+        let (center, radius) = (vec3(-0.5, 0.5, 0.0), 30.0);
+        if let Ok(flux) = radiated_flux(self, c, center, radius, player_pos.ct) {
+            s.push_str(&format!(
+                "flux through r = {radius} sphere = {:.3e}\n",
+                flux
+            ));
+        }
+    }
+
+    fn compact(&mut self, oldest: Vector4) {
+        for wl in self.world_line.iter_mut() {
+            wl.compact(oldest, Compaction::default());
+        }
+    }
+
     fn tick(&mut self, _c: f64, until: Vector4) {
         let ds = 1.0 / 128.0;
         let r = 2.0;
@@ -300,19 +847,255 @@ impl ChargeSet for CirclesChargeSet {
     }
 }
 
-fn field_strength_from_charges(
+/// Dipole moment oscillating as moment cos(ωτ) in the rest frame of its world line
+/// reached from the world frame by a pure boost, or constant if ω = 0
+pub struct PointDipole {
+    kind: DipoleKind,
+    moment: Vector3,
+    omega: f64,
+    world_line: Box<dyn ProperTime>,
+}
+
+impl PointDipole {
+    pub fn new(
+        kind: DipoleKind,
+        moment: Vector3,
+        omega: f64,
+        world_line: Box<dyn ProperTime>,
+    ) -> PointDipole {
+        PointDipole {
+            kind,
+            moment,
+            omega,
+            world_line,
+        }
+    }
+
+    /// The jerk is differenced along the world line over cτ = ±`JERK_STEP`
+    fn intersection(
+        &self,
+        c: f64,
+        intersection: Result<(Vector4, Vector3, Vector3), WorldLineError>,
+    ) -> DipoleIntersection {
+        let moment = intersection.and_then(|(x, u, a)| {
+            let jerk = self.world_line.jerk(c, x, JERK_STEP)?;
+            let s = self.world_line.proper_time(c, x.ct)?;
+            let k = self.omega / c;
+            let (sin, cos) = (k * s).sin_cos();
+            let m = match self.kind {
+                DipoleKind::Electric => self.moment / c,
+                DipoleKind::Magnetic => self.moment / (c * c),
+            };
+            let moment = [m * cos, m * (-k * sin), m * (-k * k * cos)];
+            Ok((x, [u, a, jerk], fermi_walker_moment(moment, u, a, jerk)))
+        });
+        (self.kind, moment)
+    }
+}
+
+/// Point dipoles on prescribed world lines
+pub struct DipoleSet {
+    dipoles: Vec<PointDipole>,
+}
+
+impl DipoleSet {
+    /// An electric dipole of a charge at 1 oscillating at rest with a period of 4 seconds
+    pub fn new_hertz(x: Vector3) -> DipoleSet {
+        DipoleSet {
+            dipoles: vec![PointDipole::new(
+                DipoleKind::Electric,
+                Vector3::Y_AXIS * Q,
+                std::f64::consts::FRAC_PI_2,
+                Box::new(StaticWorldLine::new(x)),
+            )],
+        }
+    }
+
+    /// A magnet along z moving with u = 0.5 along x, passing x at x_0 = 40
+    pub fn new_magnet(x: Vector3) -> DipoleSet {
+        let u = Vector3::X_AXIS * 0.5;
+        DipoleSet {
+            dipoles: vec![PointDipole::new(
+                DipoleKind::Magnetic,
+                Vector3::Z_AXIS * (Q * 4.0),
+                0.0,
+                Box::new(InertialWorldLine::new(Vector4::from_ctv(40.0, x), u)),
+            )],
+        }
+    }
+}
+
+impl ChargeSet for DipoleSet {
+    fn iter(&self, _c: f64, _player_pos: Vector4) -> Vec<ChargeIntersection> {
+        Vec::new()
+    }
+
+    fn dipoles(&self, c: f64, player_pos: Vector4) -> Vec<DipoleIntersection> {
+        self.dipoles
+            .iter()
+            .map(|d| d.intersection(c, d.world_line.past_intersection(c, player_pos)))
+            .collect()
+    }
+
+    fn dipoles_future(&self, c: f64, player_pos: Vector4) -> Vec<DipoleIntersection> {
+        self.dipoles
+            .iter()
+            .map(|d| d.intersection(c, d.world_line.future_intersection(c, player_pos)))
+            .collect()
+    }
+
+    fn radiation_info(&self, c: f64, s: &mut String, player_pos: Vector4) {
+        for (i, (_, intersection)) in self.dipoles(c, player_pos).into_iter().enumerate() {
+            if let Ok((_, _, moment)) = intersection {
+                s.push_str(&format!(
+                    "dipole {i} power = {:.3e}\n",
+                    dipole_power(moment, c)
+                ));
+            }
+        }
+    }
+}
+
+/// Charge spread over lines, each element with its own retarded intersection
+pub struct DistributionSet {
+    distributions: Vec<Box<dyn ChargeDistribution>>,
+}
+
+impl DistributionSet {
+    /// The ring of `CirclesChargeSet` as a continuous charge of 32 at half the speed of light,
+    /// with a static opposite ring so that only the current remains
+    pub fn new_ring(c: f64) -> DistributionSet {
+        let (center, r) = (vec3(-0.5, 0.5, 0.0), 2.0);
+        let frequency = 0.5 * c / (std::f64::consts::TAU * r);
+        let rotating = rotating_ring(center, r, Vector3::X_AXIS, frequency, Q * 32.0, c)
+            .expect("slower than light");
+        DistributionSet {
+            distributions: vec![
+                Box::new(rotating),
+                Box::new(static_ring(center, r, Vector3::X_AXIS, -Q * 32.0)),
+            ],
+        }
+    }
+
+    /// A charge of 8 at rest spread over a segment of length 8 along x
+    pub fn new_segment(x: Vector3) -> DistributionSet {
+        let half = Vector3::X_AXIS * 4.0;
+        DistributionSet {
+            distributions: vec![Box::new(static_segment(x - half, x + half, Q * 8.0))],
+        }
+    }
+}
+
+impl ChargeSet for DistributionSet {
+    fn iter(&self, _c: f64, _player_pos: Vector4) -> Vec<ChargeIntersection> {
+        Vec::new()
+    }
+
+    fn distributions(&self) -> &[Box<dyn ChargeDistribution>] {
+        &self.distributions
+    }
+}
+
+/// Retarded field strength of all charges and dipoles of the set at x, with its external field
+///
+/// It is an error if the field of any source has not arrived, as it jumps at that front.
+fn retarded_field_strength(
+    charges: &dyn ChargeSet,
     c: f64,
-    charges: &[EomCharge],
-    i: usize,
-    position: Vector4,
-) -> Matrix {
+    x: Vector4,
+) -> Result<Matrix, WorldLineError> {
     let mut fs = Matrix::zero();
-    for (j, charge) in charges.iter().enumerate() {
-        // ignore form self
-        if i == j {
-            continue;
+    for (q, intersection) in charges.iter(c, x) {
+        let (y, u, a) = intersection?;
+        fs = fs + Matrix::field_strength(q / c, (y - x).spatial(), u, a);
+    }
+    for (kind, intersection) in charges.dipoles(c, x) {
+        let (y, [u, a, jerk], moment) = intersection?;
+        fs = fs + Matrix::dipole_field_strength(kind, moment, (y - x).spatial(), u, a, jerk);
+    }
+    for distribution in charges.distributions() {
+        fs = fs + distribution.past_field(c, x)?.field_strength() * (1.0 / c);
+    }
+    if let Some(external) = charges.external_field() {
+        fs = fs + external.field_strength(c, x);
+    }
+    Ok(fs)
+}
+
+/// Check Maxwell's equations for the retarded field of the set at x by differences of step h,
+/// away from its sources and where all of them have arrived
+pub fn check_maxwell(
+    charges: &dyn ChargeSet,
+    c: f64,
+    x: Vector4,
+    h: f64,
+) -> Result<MaxwellResidual, WorldLineError> {
+    maxwell_residual(|x| retarded_field_strength(charges, c, x), x, h)
+}
+
+/// Energy flux of the radiation part of the charges' field out through a sphere at x_0 = ct,
+/// by the midpoint rule in θ and φ
+///
+/// The fields of all charges are added before squaring, unlike a sum of their powers.
+/// It is the instantaneous flux at ct, whose average over a period is the radiated power.
+fn radiated_flux(
+    charges: &dyn ChargeSet,
+    c: f64,
+    center: Vector3,
+    radius: f64,
+    ct: f64,
+) -> Result<f64, WorldLineError> {
+    let (n_theta, n_phi) = (16, 32);
+    let (d_theta, d_phi) = (
+        std::f64::consts::PI / n_theta as f64,
+        std::f64::consts::TAU / n_phi as f64,
+    );
+    let mut normals = Vec::with_capacity(n_theta * n_phi);
+    for i in 0..n_theta {
+        let (sin_theta, cos_theta) = ((i as f64 + 0.5) * d_theta).sin_cos();
+        for j in 0..n_phi {
+            let (sin_phi, cos_phi) = ((j as f64 + 0.5) * d_phi).sin_cos();
+            normals.push(vec3(sin_theta * cos_phi, sin_theta * sin_phi, cos_theta));
         }
-        let Some((x, u, a)) = charge.world_line.past_intersection(c, position) else {
+    }
+    let xs = normals
+        .iter()
+        .map(|&n| Vector4::from_ctv(ct, center + n * radius))
+        .collect::<Vec<_>>();
+    let mut flux = 0.0;
+    for ((n, x), sources) in normals.iter().zip(&xs).zip(charges.iter_batch(c, &xs)) {
+        let mut radiation = Matrix::zero();
+        for (q, intersection) in sources {
+            let (y, u, a) = intersection?;
+            radiation = radiation + Matrix::field_strength_parts(q / c, (y - *x).spatial(), u, a).1;
+        }
+        let poynting = Matrix::stress_energy(radiation, c).stress_energy_to_energy_flux(c);
+        // dA = r² sin θ dθ dφ, and sin θ = |n × ẑ|
+        let sin_theta = (n.x * n.x + n.y * n.y).sqrt();
+        flux += poynting.dot(*n) * radius * radius * sin_theta * d_theta * d_phi;
+    }
+    Ok(flux)
+}
+
+/// Transpose the batched intersections of each charge into the charges seen from each x
+fn batch_intersections<'a>(
+    charges: impl IntoIterator<Item = (f64, &'a dyn WorldLine)>,
+    c: f64,
+    xs: &[Vector4],
+) -> Vec<Vec<ChargeIntersection>> {
+    let mut v = vec![Vec::new(); xs.len()];
+    for (q, wl) in charges {
+        for (charges, p) in v.iter_mut().zip(wl.past_intersections(c, xs)) {
+            charges.push((q, p));
+        }
+    }
+    v
+}
+
+fn field_strength_from_charges(c: f64, charges: &[EomCharge], position: Vector4) -> Matrix {
+    let mut fs = Matrix::zero();
+    for charge in charges {
+        let Ok((x, u, a)) = charge.world_line.past_intersection(c, position) else {
             continue;
         };
         fs = fs + Matrix::field_strength(charge.q / c, x.spatial() - position.spatial(), u, a);
@@ -320,6 +1103,21 @@ fn field_strength_from_charges(
     fs
 }
 
+fn compact_charges(charges: &mut [EomCharge], oldest: Vector4) {
+    // charges also observe each other from their current positions
+    let ct = charges
+        .iter()
+        .map(|charge| {
+            let x = charge.phase_space.position;
+            x.ct - (x.spatial() - oldest.spatial()).magnitude()
+        })
+        .fold(oldest.ct, f64::min);
+    let oldest = Vector4::from_ctv(ct, oldest.spatial());
+    for charge in charges.iter_mut() {
+        charge.world_line.compact(oldest, Compaction::default());
+    }
+}
+
 fn most_past_charge_index(charges: &[EomCharge]) -> usize {
     charges
         .iter()
@@ -333,3 +1131,168 @@ fn most_past_charge_index(charges: &[EomCharge]) -> usize {
         .map(|(i, _)| i)
         .unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use rmath::CircularWorldLine;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+
+    /// Check Maxwell's equations around where the player starts at each of the times,
+    /// for the set built as the app does
+    fn assert_maxwell(mut charges: Box<dyn ChargeSet>, c: f64, player: Vector3, cts: &[f64]) {
+        let mut checked = 0;
+        for &ct in cts {
+            for offset in [
+                vec3(0.0, 0.0, 0.0),
+                vec3(3.0, -2.0, 0.0),
+                vec3(-4.0, 1.0, -5.0),
+            ] {
+                let x = Vector4::from_ctv(ct, player + offset);
+                charges.tick(c, x + Vector4::new(0.0, 0.0, 0.0, 1.0));
+                match check_maxwell(charges.as_ref(), c, x, 1.0 / 64.0) {
+                    Ok(residual) => {
+                        assert!(
+                            residual.relative() < 1e-3,
+                            "{} at {:?}",
+                            residual.relative(),
+                            x
+                        );
+                        checked += 1;
+                    }
+                    Err(e) => assert!(e.is_not_arrived(), "{:?} at {:?}", e, x),
+                }
+            }
+        }
+        assert!(checked > 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn presets_satisfy_maxwell() {
+        let c = 1.0;
+        let e = vec3(0.5, 0.5, 0.0);
+        let presets: Vec<(Box<dyn ChargeSet>, Vector3)> = vec![
+            (Box::new(StaticChargeSet::new(e)), vec3(0.0, 0.0, 30.0)),
+            (Box::new(LineOscillateCharge::new(c)), vec3(0.0, 0.0, 40.0)),
+            (Box::new(CirclesChargeSet::new(c)), vec3(0.0, 0.0, 20.0)),
+            (
+                Box::new(WorldLineChargeSet::new_kick(c, e)),
+                vec3(0.0, 0.0, 40.0),
+            ),
+            (Box::new(DipoleSet::new_hertz(e)), vec3(0.0, 0.0, 30.0)),
+            (Box::new(DipoleSet::new_magnet(e)), vec3(0.0, 0.0, 20.0)),
+            (Box::new(DistributionSet::new_ring(c)), vec3(0.0, 0.0, 20.0)),
+            (
+                Box::new(DistributionSet::new_segment(e)),
+                vec3(0.0, 0.0, 30.0),
+            ),
+        ];
+        for (charges, player) in presets {
+            assert_maxwell(charges, c, player + e, &[10.0, 20.0, 40.0, 80.0]);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn accelerated_dipoles_satisfy_maxwell() {
+        let c = 1.0;
+        let set = DipoleSet {
+            dipoles: vec![
+                PointDipole::new(
+                    DipoleKind::Electric,
+                    Vector3::Y_AXIS,
+                    0.5,
+                    Box::new(
+                        HyperbolicWorldLine::new(Vector4::zero(), Vector3::X_AXIS * 0.1).unwrap(),
+                    ),
+                ),
+                PointDipole::new(
+                    DipoleKind::Magnetic,
+                    Vector3::X_AXIS,
+                    0.5,
+                    Box::new(
+                        CircularWorldLine::new(
+                            Vector3::zero(),
+                            Vector3::X_AXIS,
+                            Vector3::Z_AXIS,
+                            0.1,
+                            c,
+                        )
+                        .unwrap(),
+                    ),
+                ),
+            ],
+        };
+        assert_maxwell(Box::new(set), c, vec3(0.0, 0.0, 10.0), &[20.0, 40.0]);
+    }
+
+    #[wasm_bindgen_test]
+    fn eom_with_static_charge_steps_in_external_field() {
+        let c = 1.0;
+        let b = 0.0125;
+        let mut set = EomWithStaticCharge::new(c, 0.0, Vector3::zero());
+        set.q = 0.0;
+        set.external = Some(Box::new(UniformField::new(
+            Vector3::zero(),
+            Vector3::Z_AXIS * b,
+        )));
+        let start = set.charges[0].phase_space.position.spatial();
+        let u = set.charges[0].phase_space.velocity;
+        // gyrating about the center at radius u / ((q / m) b)
+        let r = u.magnitude() / (Q * b);
+        let center = start - vec3(0.0, r, 0.0);
+        set.tick(c, Vector4::from_ctv(20.0, start));
+        let x = set.charges[0].phase_space.position.spatial();
+        assert!(((x - center).magnitude() - r).abs() < 1e-3 * r);
+        assert!(x.y < start.y - 0.5);
+    }
+
+    /// Integrated world lines are linear between samples, so their velocity steps at each
+    /// sample and their field is not smooth at the scale of the stencil.
+    /// Check them with their external fields before the start of the integration arrives.
+    #[wasm_bindgen_test]
+    fn integrated_presets_satisfy_maxwell_before_they_move() {
+        let c = 1.0;
+        let e = vec3(0.5, 0.5, 0.0);
+        let presets: Vec<(Box<dyn ChargeSet>, Vector3, f64)> = vec![
+            (
+                Box::new(EomChargeSet::new_fixed_two_charges(c, -30.0)),
+                vec3(0.0, 0.0, 30.0),
+                -30.0,
+            ),
+            (
+                Box::new(EomWithStaticCharge::new(c, -20.0, e)),
+                vec3(0.0, 0.0, 20.0),
+                -20.0,
+            ),
+            (
+                Box::new(EomWithStaticCharge::new_atom(c, e)),
+                vec3(0.0, 0.0, 20.0),
+                0.0,
+            ),
+            (
+                Box::new(EomChargeSet::new_synchrotron(c, e)),
+                vec3(0.0, 0.0, 30.0),
+                0.0,
+            ),
+            (
+                Box::new(EomChargeSet::new_cyclotron(c, e)),
+                vec3(0.0, 0.0, 20.0),
+                0.0,
+            ),
+            (
+                Box::new(EomChargeSet::new_drift(c, e)),
+                vec3(0.0, 0.0, 30.0),
+                0.0,
+            ),
+            (
+                Box::new(EomChargeSet::new_wave(c, e)),
+                vec3(0.0, 0.0, 30.0),
+                0.0,
+            ),
+        ];
+        for (charges, player, start) in presets {
+            assert_maxwell(charges, c, player + e, &[start + 2.0, start + 8.0]);
+        }
+    }
+}
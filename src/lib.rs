@@ -57,6 +57,54 @@ impl App {
         self.0.change_poynting_on(poynting_on);
     }
 
+    pub fn change_potential_on(&mut self, potential_on: bool) {
+        self.0.change_potential_on(potential_on);
+    }
+
+    pub fn change_energy_on(&mut self, energy_on: bool) {
+        self.0.change_energy_on(energy_on);
+    }
+
+    pub fn change_stress_on(&mut self, stress_on: bool) {
+        self.0.change_stress_on(stress_on);
+    }
+
+    pub fn change_classify_on(&mut self, classify_on: bool) {
+        self.0.change_classify_on(classify_on);
+    }
+
+    pub fn change_maxwell_on(&mut self, maxwell_on: bool) {
+        self.0.change_maxwell_on(maxwell_on);
+    }
+
+    pub fn change_flux_on(&mut self, flux_on: bool) {
+        self.0.change_flux_on(flux_on);
+    }
+
+    pub fn change_clock_on(&mut self, clock_on: bool) {
+        self.0.change_clock_on(clock_on);
+    }
+
+    pub fn change_field_mode(&mut self, mode: &str) {
+        self.0.change_field_mode(mode);
+    }
+
+    pub fn change_integrator(&mut self, integrator: &str) {
+        self.0.change_integrator(integrator);
+    }
+
+    pub fn change_step_control(&mut self, adaptive: bool) {
+        self.0.change_step_control(adaptive);
+    }
+
+    pub fn change_fermi_walker(&mut self, fermi_walker: bool) {
+        self.0.change_fermi_walker(fermi_walker);
+    }
+
+    pub fn change_field_component(&mut self, component: &str) {
+        self.0.change_field_component(component);
+    }
+
     pub fn change_arrow_length_factor(&mut self, f: f64) {
         self.0.change_arrow_length_factor(f);
     }
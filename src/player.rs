@@ -1,10 +1,18 @@
 use crate::key::{GestureEvent, KeyManager};
-use rmath::{Deg, Matrix, PhaseSpace, Quaternion, Rad, Vector2, Vector3, Vector4};
+use rmath::{
+    Deg, FermiWalkerFrame, Integrator, Matrix, PhaseSpace, Quaternion, Rad, Vector2, Vector3,
+    Vector4,
+};
 
 pub struct Player {
     phase_space: PhaseSpace,
     quaternion: Quaternion,
     breaking: bool,
+    integrator: Integrator,
+    /// Gyroscope axes carried along the world line
+    frame: FermiWalkerFrame,
+    /// Turn the view with the gyroscope axes instead of keeping it fixed to the world axes
+    fermi_walker: bool,
 }
 
 impl Player {
@@ -13,6 +21,9 @@ impl Player {
             phase_space: PhaseSpace::new(Vector3::zero(), Vector4::from_ctv(0.0, x)),
             quaternion: Quaternion::one(),
             breaking: false,
+            integrator: Integrator::default(),
+            frame: FermiWalkerFrame::new(),
+            fermi_walker: false,
         }
     }
 
@@ -30,7 +41,16 @@ impl Player {
             + self.get_viscous_acceleration() * if self.breaking { 3.0 } else { 0.0 } * c;
         let a = f_over_m / c / c;
         let ds = dt * c;
-        self.phase_space.tick(ds, a);
+        // a is in the rest frame, which turns with u during the step
+        let u0 = self.phase_space.velocity;
+        self.phase_space.step(self.integrator, ds, |_, u| {
+            (Matrix::lorentz(-u) * Vector4::from_acceleration(a)).spatial()
+        });
+        let rotation = self.frame.transport(u0, self.phase_space.velocity);
+        if self.fermi_walker {
+            // rest frame axes seen from the view turn opposite to the gyroscope
+            self.quaternion *= rotation.inverse();
+        }
 
         if let Some(q) = self.get_user_key_input_rotation_velocity(dt, key) {
             self.quaternion *= q;
@@ -40,6 +60,19 @@ impl Player {
         }
     }
 
+    pub fn change_integrator(&mut self, integrator: Integrator) {
+        self.integrator = integrator;
+    }
+
+    pub fn change_fermi_walker(&mut self, fermi_walker: bool) {
+        self.fermi_walker = fermi_walker;
+    }
+
+    /// Thomas–Wigner rotation accumulated by the gyroscope axes
+    pub fn wigner_rotation(&self) -> Quaternion {
+        self.frame.rotation()
+    }
+
     pub fn rot_matrix(&self) -> Matrix {
         Matrix::from(self.quaternion)
     }